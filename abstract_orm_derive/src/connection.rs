@@ -0,0 +1,133 @@
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use syn::Ident;
+
+/// The diesel backend the generated methods run on, `mysql` unless
+/// set with `#[orm(backend = ...)]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Mysql,
+    Postgres,
+    Sqlite,
+}
+
+impl Backend {
+    pub fn from_ident(ident: &Ident) -> Option<Backend> {
+        match ident.to_string().as_str() {
+            "mysql" => Some(Backend::Mysql),
+            "postgres" => Some(Backend::Postgres),
+            "sqlite" => Some(Backend::Sqlite),
+            _ => None,
+        }
+    }
+
+    pub fn backend_type(&self) -> TokenStream2 {
+        match self {
+            Backend::Mysql => quote!{::diesel::mysql::Mysql},
+            Backend::Postgres => quote!{::diesel::pg::Pg},
+            Backend::Sqlite => quote!{::diesel::sqlite::Sqlite},
+        }
+    }
+
+    /// SQL reading the id the last insert on the connection generated.
+    pub fn last_insert_id_sql(&self) -> &'static str {
+        match self {
+            Backend::Mysql => "CAST(LAST_INSERT_ID() AS SIGNED)",
+            Backend::Postgres => "lastval()",
+            Backend::Sqlite => "last_insert_rowid()",
+        }
+    }
+
+    pub fn connection_type(&self) -> TokenStream2 {
+        match self {
+            Backend::Mysql => quote!{::diesel::mysql::MysqlConnection},
+            Backend::Postgres => quote!{::diesel::pg::PgConnection},
+            Backend::Sqlite => quote!{::diesel::sqlite::SqliteConnection},
+        }
+    }
+}
+
+/// The kind of connection a generated method is written against.
+///
+/// Sync methods are always emitted, the `async` cargo feature adds an
+/// `_async` twin of every method running through `diesel_async`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ConnKind {
+    Sync,
+    Async,
+}
+
+impl ConnKind {
+    pub fn enabled() -> Vec<ConnKind> {
+        let mut kinds = vec![ConnKind::Sync];
+        if cfg!(feature = "async") {
+            kinds.push(ConnKind::Async);
+        }
+        kinds
+    }
+
+    pub fn fn_ident(&self, ident: &Ident) -> Ident {
+        match self {
+            ConnKind::Sync => ident.clone(),
+            ConnKind::Async => Ident::new(&format!("{}_async", ident), Span::call_site()),
+        }
+    }
+
+    pub fn asyncness(&self) -> TokenStream2 {
+        match self {
+            ConnKind::Sync => quote!{},
+            ConnKind::Async => quote!{async},
+        }
+    }
+
+    pub fn conn_type(&self, backend: Backend) -> TokenStream2 {
+        match self {
+            ConnKind::Sync => {
+                let connection_type = backend.connection_type();
+                quote!{&mut impl ::abstract_orm::ConnectionSource<#connection_type>}
+            },
+            ConnKind::Async => {
+                let backend_type = backend.backend_type();
+                quote!{&mut impl ::diesel_async::AsyncConnection<Backend = #backend_type>}
+            },
+        }
+    }
+
+    /// Rebinds the `ConnectionSource` `conn` of a sync method reading from
+    /// the database to its reader, `on_error` being `?` or an `.expect(...)`.
+    /// Async methods take a connection and use it as is.
+    pub fn reader(&self, on_error: TokenStream2) -> TokenStream2 {
+        self.checkout(quote!{reader}, on_error)
+    }
+
+    /// `reader` for a method writing to the database.
+    pub fn writer(&self, on_error: TokenStream2) -> TokenStream2 {
+        self.checkout(quote!{writer}, on_error)
+    }
+
+    fn checkout(&self, method: TokenStream2, on_error: TokenStream2) -> TokenStream2 {
+        match self {
+            ConnKind::Sync => quote!{
+                let mut conn = ::abstract_orm::ConnectionSource::#method(conn)#on_error;
+                let conn = &mut *conn;
+            },
+            ConnKind::Async => quote!{},
+        }
+    }
+
+    pub fn dot_await(&self) -> TokenStream2 {
+        match self {
+            ConnKind::Sync => quote!{},
+            ConnKind::Async => quote!{.await},
+        }
+    }
+
+    /// Runs `query` with the given `RunQueryDsl` method on `conn`.
+    /// The method is called through its fully qualified path so it never
+    /// clashes with a diesel or diesel_async `RunQueryDsl` import on the caller side.
+    pub fn run(&self, query: TokenStream2, method: TokenStream2) -> TokenStream2 {
+        match self {
+            ConnKind::Sync => quote!{ ::diesel::RunQueryDsl::#method(#query, conn) },
+            ConnKind::Async => quote!{ ::diesel_async::RunQueryDsl::#method(#query, conn).await },
+        }
+    }
+}
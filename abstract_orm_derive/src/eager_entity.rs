@@ -4,30 +4,42 @@ use crate::util;
 
 pub struct EagerEntityAST {
     original_type: Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
     eager_rel_fields: Vec<TokenStream2>,
     rel_params: Vec<Ident>,
     rel_params_t: Vec<Type>,
-    many_to_one_params_t: Vec<Type>,
-    many_to_one_params: Vec<Ident>,
     eager_rel_default_assign: Vec<TokenStream2>,
     one_to_many_data_assign: Vec<TokenStream2>,
+    derives: Vec<syn::Path>,
+    serde_attrs: Vec<syn::Attribute>,
 }
 
 impl EagerEntityAST {
 
-    pub fn new(original_type: &Ident) -> Self {
+    pub fn new(original_type: &Ident, vis: &syn::Visibility, generics: &syn::Generics) -> Self {
         Self {
             original_type: original_type.clone(),
+            vis: vis.clone(),
+            generics: generics.clone(),
             eager_rel_fields: Vec::new(),
             rel_params: Vec::new(),
             rel_params_t: Vec::new(),
-            many_to_one_params_t: Vec::new(),
-            many_to_one_params: Vec::new(),
             eager_rel_default_assign: Vec::new(),
             one_to_many_data_assign: Vec::new(),
+            derives: util::default_derives(&["Serialize"], &["Clone"]),
+            serde_attrs: Vec::new(),
         }
     }
 
+    pub fn set_derives(&mut self, derives: Vec<syn::Path>){
+        self.derives = derives;
+    }
+
+    pub fn push_serde_attr(&mut self, attr: &syn::Attribute){
+        self.serde_attrs.push(attr.clone());
+    }
+
     pub fn push_many_to_one(&mut self, field_name:&Ident, type_name:Type){
         let gen_field_name = field_name.to_string().replace("_id", "");
         let gen_field_ident =
@@ -51,31 +63,42 @@ impl EagerEntityAST {
         });
     }
 
-    pub fn push_many_to_many(&mut self, field_name:&Ident, type_name:&Type, join_type: &Type){
+    /// A relation filled by a query of its own after the `find_all_eager`
+    /// join, one with a `where` condition or a `has_many_through` as its table
+    /// is already joined for another relation, or one `with_link`.
+    pub fn push_scoped(&mut self, field_name:Ident, type_name:Type){
+        self.eager_rel_fields.push(quote! { pub #field_name: Vec<#type_name>, });
+        self.eager_rel_default_assign.push(quote! { #field_name: Vec::new(),});
+    }
+
+    pub fn push_many_to_many(&mut self, field_name:&Ident, type_name:&Type, _join_type: &Type){
         self.push_one_to_many(field_name.clone(), type_name.clone());
     }
 
     pub fn build(self)->TokenStream2 {
-        let Self {original_type, eager_rel_fields, rel_params, rel_params_t, eager_rel_default_assign, one_to_many_data_assign, ..} = self;
+        let Self {original_type, vis, generics, eager_rel_fields, rel_params, rel_params_t, eager_rel_default_assign, one_to_many_data_assign, derives, serde_attrs, ..} = self;
         let ident_with_all = util::format_ident("{}WithAll", &original_type);
-        if eager_rel_fields.len() == 0 {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        if eager_rel_fields.is_empty() {
             return quote!{};
         }
-        return quote!{
-            #[derive(Serialize, Clone)]
-            pub struct #ident_with_all {
-                #[serde(flatten)]
-                pub self_data: #original_type,
+        let derive_attrs = util::derive_attrs(&derives, &serde_attrs);
+        let flatten = if util::derives_serde(&derives) { quote!{#[serde(flatten)]} } else { quote!{} };
+        quote!{
+            #derive_attrs
+            #vis struct #ident_with_all #generics #where_clause {
+                #flatten
+                pub self_data: #original_type #ty_generics,
                 #(#eager_rel_fields)*
             }
-            impl #ident_with_all {
-                pub fn new_builder((self_data, #(#rel_params),*):(#original_type,#(#rel_params_t),* )) -> Self {
+            impl #impl_generics #ident_with_all #ty_generics #where_clause {
+                pub fn new_builder((self_data, #(#rel_params),*):(#original_type #ty_generics,#(#rel_params_t),* )) -> Self {
                     Self {self_data, #(#eager_rel_default_assign)*}
                 }
-                pub fn insert_data(&mut self, (self_data, #(#rel_params),*):(#original_type,#(#rel_params_t),* )){
+                pub fn insert_data(&mut self, (self_data, #(#rel_params),*):(#original_type #ty_generics,#(#rel_params_t),* )){
                     #(#one_to_many_data_assign)*
                 }
             }
-        };
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,1911 @@
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use proc_macro_error::abort;
+use syn::{Ident, Type, Token, punctuated::Punctuated, spanned::Spanned};
+use crate::util;
+use crate::connection::{Backend, ConnKind};
+use crate::orm_attr::ChildOrder;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// How a relation field is stored, the related type is kept next to it.
+enum RelationKind {
+    /// `many_to_one` on the foreign key field, which is an `Option` when `optional`
+    ManyToOne { foreign_key: Ident, optional: bool },
+    OneToMany,
+    /// `with_link` keeps the rows of `join_type` next to the related ones
+    ManyToMany { join_type: Type, with_link: bool },
+    /// `has_many_through`, `join_type` is the target of the `one_to_many` `through`
+    Through { join_type: Type, through: Ident },
+}
+
+pub struct EntityImplAST {
+    original_type: Ident,
+    generics: syn::Generics,
+    borrowed_insert: bool,
+    rel_fields_lazy_get: Vec<TokenStream2>,
+    pub table_name:Option<Type>,
+    schema: Option<syn::Path>,
+    backend: Backend,
+    join_statements: BTreeMap<Ident,TokenStream2>,
+    relation_kinds: BTreeMap<Ident,(RelationKind, Type)>,
+    rel_types_map: BTreeMap<Ident,Type>,
+    rel_with_join_map: BTreeMap<Ident,Type>,
+    model_pk: Vec<Ident>,
+    model_pk_t: BTreeMap<Ident,Type>,
+    rel_collect_types: Vec<Type>,
+    relation_asserts: Vec<TokenStream2>,
+    relation_impls: Vec<TokenStream2>,
+    related_types: BTreeSet<String>,
+    soft_delete: Option<Ident>,
+    soft_delete_type: Option<Type>,
+    created_at: Option<Ident>,
+    updated_at: Option<Ident>,
+    update_columns: Vec<Ident>,
+    version: Option<(Ident, Option<Type>)>,
+    hooks: bool,
+    validated: bool,
+    child_orders: BTreeMap<Ident,(Type, ChildOrder)>,
+    /// `#[orm(unique)]` and `#[orm(index)]` fields, `true` for unique ones
+    lookups: BTreeMap<Ident,(Type, bool)>,
+    relation_conditions: BTreeMap<Ident,syn::Expr>,
+}
+
+/// `right` joined to `left` on their `joinable!` condition, leaving out its
+/// soft deleted rows and those failing the `where` of a scoped relation in
+/// the `ON` clause so a left join keeps the row of `left`.
+fn join_target(left: TokenStream2, right: &Type, condition: Option<&syn::Expr>) -> TokenStream2 {
+    let condition = condition.map(|condition| quote!{ .and(#condition) });
+    quote!{
+        #right::get_table_ref().on(
+            ::abstract_orm::join_on(#left, #right::get_table_ref())
+                .and(<#right as ::abstract_orm::SoftDelete>::not_deleted())
+                #condition
+        )
+    }
+}
+
+impl EntityImplAST {
+    pub fn new(original_type: &Ident, generics: &syn::Generics) -> Self {
+        Self {
+            original_type: original_type.clone(),
+            generics: generics.clone(),
+            borrowed_insert: false,
+            rel_fields_lazy_get: Vec::new(),
+            table_name: None,
+            schema: None,
+            backend: Backend::Mysql,
+            model_pk: Vec::new(),
+            model_pk_t: BTreeMap::new(),
+            join_statements: BTreeMap::new(),
+            relation_kinds: BTreeMap::new(),
+            rel_types_map: BTreeMap::new(),
+            rel_with_join_map: BTreeMap::new(),
+            rel_collect_types: Vec::new(),
+            relation_asserts: Vec::new(),
+            relation_impls: Vec::new(),
+            related_types: BTreeSet::new(),
+            soft_delete: None,
+            soft_delete_type: None,
+            created_at: None,
+            updated_at: None,
+            update_columns: Vec::new(),
+            version: None,
+            hooks: false,
+            validated: false,
+            child_orders: BTreeMap::new(),
+            lookups: BTreeMap::new(),
+            relation_conditions: BTreeMap::new(),
+        }
+    }
+    
+    pub fn set_table_name(&mut self, table_name:syn::Type){
+        self.table_name = Some(table_name);
+    }
+
+    pub fn set_schema(&mut self, schema:syn::Path){
+        self.schema = Some(schema);
+    }
+
+    pub fn set_backend(&mut self, backend: Backend){
+        self.backend = backend;
+    }
+
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    pub fn set_borrowed_insert(&mut self){
+        self.borrowed_insert = true;
+    }
+
+    pub fn set_soft_delete(&mut self, column: Ident){
+        self.soft_delete = Some(column);
+    }
+
+    pub fn set_created_at(&mut self, column: Ident){
+        self.created_at = Some(column);
+    }
+
+    pub fn set_updated_at(&mut self, column: Ident){
+        self.updated_at = Some(column);
+    }
+
+    /// Whether `field_name` is a timestamp column filled by the generated
+    /// code, which leaves it out of `NewX`.
+    pub fn is_timestamp(&self, field_name: &Ident) -> bool {
+        self.created_at.as_ref() == Some(field_name) || self.updated_at.as_ref() == Some(field_name)
+    }
+
+    pub fn set_hooks(&mut self){
+        self.hooks = true;
+    }
+
+    pub fn set_validated(&mut self){
+        self.validated = true;
+    }
+
+    /// Whether `insert` can fail outside of diesel and returns an `OrmError`.
+    fn insert_returns_orm_error(&self) -> bool {
+        self.hooks || self.validated
+    }
+
+    pub fn set_version(&mut self, column: Ident){
+        self.version = Some((column, None));
+    }
+
+    pub fn is_version(&self, field_name: &Ident) -> bool {
+        matches!(&self.version, Some((column, _)) if column == field_name)
+    }
+
+    pub fn search_version_type(&mut self, field_name: &Ident, type_value: &Type){
+        if let Some((column, version_type)) = &mut self.version {
+            if column == field_name {
+                *version_type = Some(type_value.clone());
+            }
+        }
+    }
+
+    pub fn search_soft_delete_type(&mut self, field_name: &Ident, type_value: &Type){
+        if self.soft_delete.as_ref() == Some(field_name) {
+            self.soft_delete_type = Some(util::extract_type_from_option(type_value));
+        }
+    }
+
+    /// Adds a `find_by_x` lookup on `field_name`, or `find_all_by_x` unless `unique`.
+    pub fn push_lookup(&mut self, field_name: &Ident, type_name: &Type, unique: bool){
+        self.lookups.insert(field_name.clone(), (type_name.clone(), unique));
+    }
+
+    /// Adds `field_name` to the columns `update` writes, unless it is part of
+    /// the primary key or the soft delete column.
+    pub fn push_update_column(&mut self, field_name: &Ident){
+        if self.pk_columns().contains(field_name) || self.soft_delete.as_ref() == Some(field_name) {
+            return;
+        }
+        self.update_columns.push(field_name.clone());
+    }
+
+    /// Filter leaving out the soft deleted rows of the entity, empty
+    /// without `#[orm(soft_delete = ...)]`.
+    fn not_deleted_filter(&self, table_name: &TokenStream2) -> TokenStream2 {
+        match &self.soft_delete {
+            Some(column) => quote!{ .filter(#table_name::#column.is_null()) },
+            None => quote!{},
+        }
+    }
+
+    /// The same filter as a `ProjectionFilter`.
+    fn not_deleted_projection_filter(&self, table_name: &TokenStream2) -> TokenStream2 {
+        match &self.soft_delete {
+            Some(column) => quote!{ Some(Box::new(#table_name::#column.is_null().nullable())) },
+            None => quote!{ None },
+        }
+    }
+
+    /// Columns identifying the row of `self`.
+    fn pk_columns(&self) -> Vec<Ident> {
+        if self.model_pk.is_empty() {
+            return vec![Ident::new("id", Span::call_site())];
+        }
+        self.model_pk.clone()
+    }
+
+    /// Path of the diesel table module, qualified with the configured schema
+    /// module when `table_name` is a bare identifier.
+    pub fn table_path(&self) -> Option<TokenStream2> {
+        let table_name = self.table_name.as_ref()?;
+        match (&self.schema, table_name) {
+            (Some(schema), syn::Type::Path(typepath)) if typepath.path.get_ident().is_some() => {
+                Some(quote!{#schema::#table_name})
+            },
+            _ => Some(quote!{#table_name}),
+        }
+    }
+
+
+    pub fn get_table_name_ident(&self) -> Option<syn::Ident> {
+        match &self.table_name {
+            Some(syn::Type::Path(typepath)) => {
+                typepath.path.segments.last().map(|last| last.ident.clone())
+            },
+            _=>{None}
+        }
+    }
+
+    pub fn parse_diesel_attr(&mut self, meta_args: &syn::MetaList){
+        if let Ok(arguments_parsed) = meta_args.parse_args_with(Punctuated::<syn::TypeParam, Token![,]>::parse_terminated) {
+            for arg in arguments_parsed {
+                if arg.ident.to_string().eq("table_name"){
+                    if let Some(type_name) = arg.default.clone() {
+                        self.set_table_name(type_name);
+                    }
+                }
+            }
+        }
+        if let Ok(argument_parsed) = meta_args.parse_args::<syn::ExprCall>() {
+            if let syn::Expr::Path(function_path) = *argument_parsed.func {
+                if function_path.path.is_ident("primary_key") {
+                    for f_arg in argument_parsed.args {
+                        if let syn::Expr::Path(f_arg_path) = f_arg {
+                            if let Some(path_ident) = f_arg_path.path.get_ident(){
+                                self.model_pk.push(path_ident.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether `field_name` is the single column primary key, which is left to the
+    /// database on insert. Composite keys, as used by join tables, are inserted.
+    pub fn is_single_pk(&self, field_name: &Ident) -> bool {
+        match self.model_pk.as_slice() {
+            [] => field_name == "id",
+            [pk_ident] => pk_ident == field_name,
+            _ => false,
+        }
+    }
+
+    pub fn search_pk_type(&mut self, field_name: &Ident, type_value: &Type ){
+        for pk_ident in &self.model_pk {
+            if pk_ident == field_name {
+                self.model_pk_t.insert(pk_ident.clone(), type_value.clone());
+            }
+        }
+    }
+
+    pub fn push_many_to_one(&mut self, field_name:&Ident, type_name:&Type){
+        let sanitized_type = util::extract_type_from_option(type_name);
+        let gen_field_name = field_name.to_string().replace("_id", "");
+        let gen_field_ident =
+            syn::Ident::new(&gen_field_name, Span::call_site());
+        let get_ident = util::format_ident("get_{}", &gen_field_ident);
+        for kind in ConnKind::enabled() {
+            let fn_ident = kind.fn_ident(&get_ident);
+            let find_ident = kind.fn_ident(&syn::Ident::new("find", Span::call_site()));
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let dot_await = kind.dot_await();
+            if util::type_is_option(type_name) {
+                self.rel_fields_lazy_get.push(quote! { 
+                    pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> #type_name {
+                        if let Some(fk_value) = self.#field_name {
+                            return #sanitized_type::#find_ident(fk_value, conn)#dot_await.expect("could not fetch relation");
+                        } else {
+                            return None;
+                        }
+                    }
+                });
+            } else {
+                // The parent of a row is loaded even when it is soft deleted,
+                // `find` would leave the getter without a value to return.
+                let backend_type = self.backend.backend_type();
+                let reader = kind.reader(quote!{.expect("could not get a connection")});
+                let first = kind.run(quote!{
+                    ::diesel::QueryDsl::select(
+                        ::diesel::QueryDsl::find(#sanitized_type::get_table_ref(), self.#field_name),
+                        <#sanitized_type as ::diesel::SelectableHelper<#backend_type>>::as_select(),
+                    )
+                }, quote!{first::<#sanitized_type>});
+                self.rel_fields_lazy_get.push(quote! { 
+                    pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> #type_name {
+                        #reader
+                        return #first.expect("could not fetch relation");
+                    }
+                });
+            }
+        }
+        self.relation_asserts.push(quote_spanned!{sanitized_type.span()=>
+            relation_needs_joinable(Self::get_table_ref(), #sanitized_type::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(Self::get_table_ref(), #sanitized_type::get_table_ref());
+        });
+        let load_parent = if util::type_is_option(type_name) {
+            quote!{
+                match self.#field_name {
+                    Some(fk_value) => <#sanitized_type>::find(fk_value, conn).map_err(::abstract_orm::OrmError::from),
+                    None => Ok(None),
+                }
+            }
+        } else {
+            quote!{
+                <#sanitized_type>::find(self.#field_name, conn).map_err(::abstract_orm::OrmError::from)
+            }
+        };
+        self.push_relation_impl("BelongsTo", &sanitized_type, quote!{
+            fn load_parent(&self, conn: &mut Self::Connection) -> Result<Option<#sanitized_type>, ::abstract_orm::OrmError> {
+                return #load_parent;
+            }
+        });
+        self.relation_kinds.insert(gen_field_ident.clone(), (RelationKind::ManyToOne {
+            foreign_key: field_name.clone(),
+            optional: util::type_is_option(type_name),
+        }, sanitized_type.clone()));
+        let original_type = &self.original_type;
+        let join = join_target(quote!{#original_type::get_table_ref()}, &sanitized_type, None);
+        if util::type_is_option(type_name) {
+            self.join_statements.insert(gen_field_ident.clone(), quote!{
+                .left_join(#join)
+            });
+            self.rel_collect_types.push(util::make_type_option(&sanitized_type));
+            self.rel_types_map.insert(gen_field_ident.clone(), util::make_type_option(&sanitized_type));
+        } else {
+            self.join_statements.insert(gen_field_ident.clone(), quote!{
+                .inner_join(#join)
+            });
+            self.rel_collect_types.push(type_name.clone());
+            self.rel_types_map.insert(gen_field_ident.clone(), type_name.clone());
+        }
+    }
+    pub fn push_one_to_many(&mut self, field_name:&Ident, type_name:&Type, order: Option<ChildOrder>, condition: Option<syn::Expr>){
+        if let Some(order) = order {
+            self.child_orders.insert(field_name.clone(), (type_name.clone(), order));
+        }
+        if let Some(condition) = condition {
+            self.relation_conditions.insert(field_name.clone(), condition);
+        }
+        let scope = self.scope_filter(field_name);
+        let get_ident = util::format_ident("get_{}", field_name);
+        for kind in ConnKind::enabled() {
+            let fn_ident = kind.fn_ident(&get_ident);
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let load = kind.run(quote!{
+                #type_name::belonging_to(&self)
+                .filter(<#type_name as ::abstract_orm::SoftDelete>::not_deleted())
+                #scope
+                .select(#type_name::as_select())
+            }, quote!{load});
+            let children = self.sorted_children(field_name, quote!{
+                #load.expect("error fetching #ident from #type_name")
+            });
+            let reader = kind.reader(quote!{.expect("could not get a connection")});
+            self.rel_fields_lazy_get.push(quote! { 
+                pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> Vec<#type_name> {
+                    #reader
+                    return #children;
+                }
+            });
+        }
+        self.relation_asserts.push(quote_spanned!{type_name.span()=>
+            relation_needs_joinable(Self::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(Self::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_belongs_to::<#type_name, Self>();
+        });
+        if !self.relation_conditions.contains_key(field_name) {
+            let load = ConnKind::Sync.run(quote!{
+                #type_name::belonging_to(&self)
+                .filter(<#type_name as ::abstract_orm::SoftDelete>::not_deleted())
+                .select(#type_name::as_select())
+            }, quote!{load});
+            let children = if self.child_orders.contains_key(field_name) {
+                let children = self.sorted_children(field_name, quote!{
+                    #load.map_err(::abstract_orm::OrmError::from)?
+                });
+                quote!{ Ok(#children) }
+            } else {
+                quote!{ #load.map_err(::abstract_orm::OrmError::from) }
+            };
+            self.push_relation_impl("HasMany", type_name, quote!{
+                fn load_children(&self, conn: &mut Self::Connection) -> Result<Vec<#type_name>, ::abstract_orm::OrmError> {
+                    return #children;
+                }
+            });
+            self.rel_collect_types.push(util::make_type_option(type_name));
+        }
+        self.relation_kinds.insert(field_name.clone(), (RelationKind::OneToMany, type_name.clone()));
+        let original_type = &self.original_type;
+        let join = join_target(quote!{#original_type::get_table_ref()}, type_name, self.relation_conditions.get(field_name));
+        self.join_statements.insert(field_name.clone(), quote!{
+            .left_join(#join)
+        });
+        if util::type_contains(type_name, "To") {
+            self.rel_with_join_map.insert(field_name.clone(), type_name.clone());
+        }
+        self.rel_types_map.insert(field_name.clone(), util::make_type_option(type_name));
+    }
+
+    pub fn push_many_to_many(&mut self, field_name:&Ident, type_name:&Type, join_type: &Type, condition: Option<syn::Expr>, with_link: bool){
+        if let Some(condition) = condition {
+            self.relation_conditions.insert(field_name.clone(), condition);
+        }
+        let scope = self.scope_filter(field_name);
+        let (item_type, select) = if with_link {
+            (quote!{(#join_type, #type_name)}, quote!{(#join_type::as_select(), #type_name::as_select())})
+        } else {
+            (quote!{#type_name}, quote!{#type_name::as_select()})
+        };
+        let get_ident = util::format_ident("get_{}", field_name);
+        for kind in ConnKind::enabled() {
+            let fn_ident = kind.fn_ident(&get_ident);
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let load = kind.run(quote!{
+                #join_type::belonging_to(&self)
+                .inner_join(#type_name::get_table_ref())
+                .filter(<#join_type as ::abstract_orm::SoftDelete>::not_deleted())
+                .filter(<#type_name as ::abstract_orm::SoftDelete>::not_deleted())
+                #scope
+                .select(#select)
+            }, quote!{load});
+            let reader = kind.reader(quote!{.expect("could not get a connection")});
+            self.rel_fields_lazy_get.push(quote! { 
+                pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> Vec<#item_type> {
+                    #reader
+                    return #load.expect("error running query to fetch many to many relationship");
+                }
+            });
+        }
+        self.relation_asserts.push(quote_spanned!{type_name.span()=>
+            relation_needs_joinable(Self::get_table_ref(), #join_type::get_table_ref());
+            relation_needs_joinable(#join_type::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(Self::get_table_ref(), #join_type::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(#join_type::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(Self::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_belongs_to::<#join_type, Self>();
+        });
+        if !self.relation_conditions.contains_key(field_name) && !with_link {
+            let load = ConnKind::Sync.run(quote!{
+                #join_type::belonging_to(&self)
+                .inner_join(#type_name::get_table_ref())
+                .filter(<#join_type as ::abstract_orm::SoftDelete>::not_deleted())
+                .filter(<#type_name as ::abstract_orm::SoftDelete>::not_deleted())
+                .select(#type_name::as_select())
+            }, quote!{load});
+            self.push_relation_impl("HasMany", type_name, quote!{
+                fn load_children(&self, conn: &mut Self::Connection) -> Result<Vec<#type_name>, ::abstract_orm::OrmError> {
+                    return #load.map_err(::abstract_orm::OrmError::from);
+                }
+            });
+            self.rel_collect_types.push(util::make_type_option(type_name));
+        }
+        //self.rel_collect_types.push(util::make_type_option(join_type));
+        self.relation_kinds.insert(field_name.clone(), (RelationKind::ManyToMany { join_type: join_type.clone(), with_link }, type_name.clone()));
+        let original_type = &self.original_type;
+        let join_link = join_target(quote!{#original_type::get_table_ref()}, join_type, None);
+        let join_related = join_target(quote!{#join_type::get_table_ref()}, type_name, self.relation_conditions.get(field_name));
+        self.join_statements.insert(field_name.clone(), quote!{
+            .left_join(#join_link)
+            .left_join(#join_related)
+        });
+        let select_type = if with_link {
+            util::make_type_option(&syn::parse_quote!{ (#join_type, #type_name) })
+        } else {
+            util::make_type_option(type_name)
+        };
+        self.rel_types_map.insert(field_name.clone(), select_type);
+    }
+
+    /// `has_many_through`, the entities of `type_name` joined to those of the
+    /// `one_to_many` relation `through` declared before it.
+    pub fn push_has_many_through(&mut self, field_name:&Ident, type_name:&Type, through: &Ident, condition: Option<syn::Expr>){
+        let join_type = match self.relation_kinds.get(through) {
+            Some((RelationKind::OneToMany, join_type)) => join_type.clone(),
+            _ => abort!(through.span(), "`{}` is not a one_to_many relation of `{}` declared before", through, self.original_type),
+        };
+        if let Some(condition) = condition {
+            self.relation_conditions.insert(field_name.clone(), condition);
+        }
+        let through_scope = self.scope_filter(through);
+        let join_related = join_target(quote!{#join_type::get_table_ref()}, type_name, self.relation_conditions.get(field_name));
+        let get_ident = util::format_ident("get_{}", field_name);
+        for kind in ConnKind::enabled() {
+            let fn_ident = kind.fn_ident(&get_ident);
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let load = kind.run(quote!{
+                #join_type::belonging_to(&self)
+                .inner_join(#join_related)
+                .filter(<#join_type as ::abstract_orm::SoftDelete>::not_deleted())
+                #through_scope
+                .select(#type_name::as_select())
+            }, quote!{load});
+            let reader = kind.reader(quote!{.expect("could not get a connection")});
+            self.rel_fields_lazy_get.push(quote! {
+                pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> Vec<#type_name> {
+                    #reader
+                    return #load.expect("error running query to fetch has many through relationship");
+                }
+            });
+        }
+        self.relation_asserts.push(quote_spanned!{type_name.span()=>
+            relation_needs_joinable(#join_type::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(#join_type::get_table_ref(), #type_name::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(Self::get_table_ref(), #type_name::get_table_ref());
+        });
+        let original_type = &self.original_type;
+        let join_link = join_target(quote!{#original_type::get_table_ref()}, &join_type, self.relation_conditions.get(through));
+        self.join_statements.insert(field_name.clone(), quote!{
+            .left_join(#join_link)
+            .left_join(#join_related)
+        });
+        self.relation_kinds.insert(field_name.clone(), (RelationKind::Through { join_type, through: through.clone() }, type_name.clone()));
+        self.rel_types_map.insert(field_name.clone(), util::make_type_option(type_name));
+    }
+
+    /// Whether the relation `f_name` is part of the `find_all_eager` join,
+    /// scoped and `has_many_through` relations join a table already there
+    /// and `with_link` ones select the join table too.
+    fn is_eager_joined(&self, f_name: &Ident) -> bool {
+        !self.relation_conditions.contains_key(f_name) && !matches!(
+            self.relation_kinds.get(f_name),
+            Some((RelationKind::Through { .. } | RelationKind::ManyToMany { with_link: true, .. }, _))
+        )
+    }
+
+    /// `.filter(...)` of the `where` condition of a scoped relation, nothing
+    /// for the others.
+    fn scope_filter(&self, f_name: &Ident) -> Option<TokenStream2> {
+        self.relation_conditions.get(f_name).map(|condition| quote!{ .filter(#condition) })
+    }
+
+    /// Sorts the `Vec` behind `children` by the `order_by` of the relation
+    /// `f_name`, nothing for a relation without one.
+    fn sort_children(&self, f_name: &Ident, children: TokenStream2) -> TokenStream2 {
+        let (type_name, ChildOrder { field, descending }) = match self.child_orders.get(f_name) {
+            Some(order) => order,
+            None => return quote!{},
+        };
+        quote!{
+            ::abstract_orm::sort_children(#children, |child: &#type_name| &child.#field, #descending);
+        }
+    }
+
+    /// The `Vec` built by `children` sorted like `sort_children`.
+    fn sorted_children(&self, f_name: &Ident, children: TokenStream2) -> TokenStream2 {
+        if !self.child_orders.contains_key(f_name) {
+            return children;
+        }
+        let sort = self.sort_children(f_name, quote!{&mut children});
+        quote!{{
+            let mut children = #children;
+            #sort
+            children
+        }}
+    }
+
+    /// Implements the runtime relation trait `trait_name` for `related`, only
+    /// for the first relation of that kind to a given type so a second
+    /// relation to the same type does not produce a conflicting impl.
+    fn push_relation_impl(&mut self, trait_name: &str, related: &Type, body: TokenStream2) {
+        let related_key = format!("{} {}", trait_name, quote!{#related}.to_string().replace(' ', ""));
+        if !self.related_types.insert(related_key) {
+            return;
+        }
+        let trait_ident = Ident::new(trait_name, Span::call_site());
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        self.relation_impls.push(quote!{
+            impl #impl_generics ::abstract_orm::#trait_ident<#related> for #original_type #ty_generics #where_clause {
+                #body
+            }
+        });
+    }
+
+    /// Primary key type of the `Entity` impl with the pattern binding it to
+    /// the arguments of the inherent `find`.
+    fn key_type_and_pattern(&self) -> (TokenStream2, TokenStream2) {
+        if self.model_pk_t.is_empty() {
+            return (quote!{i64}, quote!{uid});
+        }
+        let pk_idents: Vec<&Ident> = self.model_pk_t.keys().collect();
+        let pk_types: Vec<&Type> = self.model_pk_t.values().collect();
+        if pk_idents.len() == 1 {
+            return (quote!{#(#pk_types)*}, quote!{#(#pk_idents)*});
+        }
+        (quote!{(#(#pk_types),*)}, quote!{(#(#pk_idents),*)})
+    }
+
+    fn make_entity_impl(&self, table_name: &TokenStream2)->TokenStream2 {
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (key_type, key_pattern) = self.key_type_and_pattern();
+        let key_args: Vec<Ident> = if self.model_pk_t.is_empty() {
+            vec![Ident::new("uid", Span::call_site())]
+        } else {
+            self.model_pk_t.keys().cloned().collect()
+        };
+        let connection_type = self.backend.connection_type();
+        let ident_save = util::format_ident("New{}", original_type);
+        let new_generics = util::insert_generics(&self.generics, self.borrowed_insert);
+        let (_, new_ty_generics, _) = new_generics.split_for_impl();
+        let (lazy_type, with_all_type) = if !self.join_statements.is_empty() {
+            let ident_lazy = util::format_ident("{}Lazy", original_type);
+            let ident_with_all = util::format_ident("{}WithAll", original_type);
+            (quote!{#ident_lazy #ty_generics}, quote!{#ident_with_all #ty_generics})
+        } else {
+            (quote!{Self}, quote!{Self})
+        };
+        let not_deleted = self.not_deleted_filter(table_name);
+        let load = ConnKind::Sync.run(quote!{
+            #table_name::table
+                #not_deleted
+                .select(Self::as_select())
+        }, quote!{load});
+        let insert = if self.insert_returns_orm_error() {
+            quote!{ #original_type::insert(data, conn) }
+        } else {
+            quote!{ #original_type::insert(data, conn).map_err(::abstract_orm::OrmError::from) }
+        };
+        quote!{
+            impl #impl_generics ::abstract_orm::Entity for #original_type #ty_generics #where_clause {
+                type Table = #table_name::table;
+                type Key = #key_type;
+                type New<'a> = #ident_save #new_ty_generics where Self: 'a;
+                type Lazy = #lazy_type;
+                type WithAll = #with_all_type;
+                type Connection = #connection_type;
+
+                fn get_table_ref() -> Self::Table {
+                    return #table_name::table;
+                }
+
+                fn find(key: Self::Key, conn: &mut Self::Connection) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+                    let #key_pattern = key;
+                    return #original_type::find(#(#key_args),*, conn).map_err(::abstract_orm::OrmError::from);
+                }
+
+                fn find_all(conn: &mut Self::Connection) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+                    return #load.map_err(::abstract_orm::OrmError::from);
+                }
+
+                fn insert<'a>(data: Self::New<'a>, conn: &mut Self::Connection) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+                    return #insert;
+                }
+            }
+        }
+    }
+
+    fn make_find_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        if !self.model_pk_t.is_empty() {
+            let mut find_params: Vec<TokenStream2> = Vec::new();
+            for (k, v) in &self.model_pk_t {
+                find_params.push(quote!{#k:#v});
+            }
+            let model_pk = &self.model_pk;
+            let fn_ident = kind.fn_ident(&Ident::new("find", Span::call_site()));
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let not_deleted = self.not_deleted_filter(table_name);
+            let first = kind.run(quote!{
+                #table_name::table
+                    #not_deleted
+                    #(.filter(#table_name::#model_pk.eq(#model_pk)))*
+            }, quote!{first::<Self>});
+            let reader = kind.reader(quote!{?});
+            quote!{
+                pub #asyncness fn #fn_ident(
+                    #(#find_params),*,
+                    conn: #conn_type,
+                ) -> Result<Option<Self>, ::diesel::result::Error> {
+                    #reader
+                    let result = #first
+                        .optional()?;
+                        
+                    Ok(result)
+                }
+            }
+        }else {
+            self.default_find_fn(table_name, kind)
+        }
+    }
+
+    fn default_find_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let fn_ident = kind.fn_ident(&Ident::new("find", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let not_deleted = self.not_deleted_filter(table_name);
+        let first = kind.run(quote!{
+            #table_name::table
+                #not_deleted
+                .filter(#table_name::id.eq(uid))
+        }, quote!{first::<Self>});
+        let reader = kind.reader(quote!{?});
+        quote!{
+            pub #asyncness fn #fn_ident(
+                uid: i64,
+                conn: #conn_type,
+            ) -> Result<Option<Self>, ::diesel::result::Error> {
+                #reader
+                let result = #first
+                    .optional()?;
+                    
+                Ok(result)
+            }
+        }
+    }
+
+    fn make_find_all_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let fn_ident = kind.fn_ident(&Ident::new("find_all", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let not_deleted = self.not_deleted_filter(table_name);
+        let load = kind.run(quote!{
+            #table_name::table
+                #not_deleted
+                .order_by(::abstract_orm::Order::with_primary_key(order))
+                .select(Self::as_select())
+        }, quote!{load});
+        let reader = kind.reader(quote!{.unwrap()});
+        quote!{
+            pub #asyncness fn #fn_ident(
+                order: Option<::abstract_orm::Order<Self>>,
+                conn: #conn_type,
+            )->Vec<Self>{
+                #reader
+                return #load
+                    .unwrap();
+            }
+        }
+    }
+
+    fn make_insert_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let ident_save = util::format_ident("New{}", &self.original_type);
+        let insert_generics = util::insert_generics(&self.generics, self.borrowed_insert);
+        let (_, save_ty_generics, _) = insert_generics.split_for_impl();
+        let fn_generics = if self.borrowed_insert { quote!{<'a>} } else { quote!{} };
+        let fn_ident = kind.fn_ident(&Ident::new("insert", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let timestamps: Vec<&Ident> = self.created_at.iter().chain(self.updated_at.iter()).collect();
+        let version_start: Vec<TokenStream2> = self.version_column().into_iter()
+            .map(|(column, version_type)| quote!{#table_name::#column.eq(<#version_type as ::std::default::Default>::default())})
+            .collect();
+        let values = if !timestamps.is_empty() || !version_start.is_empty() {
+            quote!{(&data, #(#table_name::#timestamps.eq(::diesel::dsl::now),)* #(#version_start,)*)}
+        } else {
+            quote!{&data}
+        };
+        let execute = kind.run(quote!{
+            ::diesel::insert_into(#table_name::table).values(#values)
+        }, quote!{execute});
+        let writer = kind.writer(quote!{?});
+        if self.insert_returns_orm_error() {
+            let (data_binding, before_insert, after_insert) = if self.hooks {
+                let find_inserted = self.find_inserted(kind);
+                (quote!{mut data}, quote!{
+                    <Self as ::abstract_orm::Hooks>::before_insert(&mut data)?;
+                }, quote!{
+                    if let Some(inserted) = #find_inserted {
+                        <Self as ::abstract_orm::Hooks>::after_insert(&inserted)?;
+                    }
+                })
+            } else {
+                (quote!{data}, quote!{}, quote!{})
+            };
+            let validate = self.make_validate_call(quote!{data});
+            return quote!{
+                pub #asyncness fn #fn_ident #fn_generics(
+                    #data_binding: #ident_save #save_ty_generics,
+                    conn: #conn_type,
+                ) -> Result<#ident_save #save_ty_generics, ::abstract_orm::OrmError> {
+                    #before_insert
+                    #validate
+                    #writer
+                    #execute?;
+                    #after_insert
+                    Ok(data)
+                }
+            };
+        }
+        quote!{
+            pub #asyncness fn #fn_ident #fn_generics(
+                data: #ident_save #save_ty_generics,
+                conn: #conn_type,
+            ) -> Result<#ident_save #save_ty_generics, ::diesel::result::Error> {
+                #writer
+                #execute?;
+            
+                Ok(data)
+            }
+        }
+    }
+
+    /// Checks the `#[validate(...)]` rules of `value`, empty without rules.
+    fn make_validate_call(&self, value: TokenStream2)->TokenStream2 {
+        if !self.validated {
+            return quote!{};
+        }
+        quote!{
+            #value.validate().map_err(::abstract_orm::OrmError::Validation)?;
+        }
+    }
+
+    /// Reads back the row `insert` just wrote, by its composite key taken
+    /// from the inserted data or else by the id the database generated,
+    /// which has to be an `i64` to be read with the last insert id.
+    fn find_inserted(&self, kind: ConnKind)->TokenStream2 {
+        let find_ident = kind.fn_ident(&Ident::new("find", Span::call_site()));
+        let dot_await = kind.dot_await();
+        if self.model_pk_t.len() > 1 {
+            let pk_idents: Vec<&Ident> = self.model_pk_t.keys().collect();
+            return quote!{
+                Self::#find_ident(#(::std::clone::Clone::clone(&data.#pk_idents)),*, conn)#dot_await?
+            };
+        }
+        if let Some((pk_ident, pk_type)) = self.model_pk_t.iter().next() {
+            if !util::type_is_i64(pk_type) {
+                abort!(pk_ident.span(), "`#[orm(hooks)]` reads the inserted row back by its generated key, `{}` has to be an `i64`", pk_ident);
+            }
+        }
+        let last_insert_id_sql = self.backend.last_insert_id_sql();
+        let get_id = kind.run(quote!{
+            ::diesel::select(::diesel::dsl::sql::<::diesel::sql_types::BigInt>(#last_insert_id_sql))
+        }, quote!{get_result::<i64>});
+        quote!{
+            Self::#find_ident(#get_id?, conn)#dot_await?
+        }
+    }
+
+    /// The version column with its field type, once the field is found.
+    fn version_column(&self) -> Option<(&Ident, &Type)> {
+        match &self.version {
+            Some((column, Some(version_type))) => Some((column, version_type)),
+            _ => None,
+        }
+    }
+
+    /// `update`, writing every column of `self` but the primary key and the
+    /// columns left to the database, `updated_at` is set to the current time.
+    /// With a version column the row is only written if its version is still
+    /// the one of `self`, which is then incremented.
+    fn make_update_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let update_columns = &self.update_columns;
+        let updated_at: Vec<&Ident> = self.updated_at.iter().collect();
+        let version_column = self.version_column();
+        if update_columns.is_empty() && updated_at.is_empty() && version_column.is_none() {
+            return quote!{};
+        }
+        let pk_columns = self.pk_columns();
+        let fn_ident = kind.fn_ident(&Ident::new("update", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let original_type = self.original_type.to_string();
+        let (version_filter, version_set) = match version_column {
+            Some((column, _)) => (
+                quote!{ .filter(#table_name::#column.eq(&self.#column)) },
+                quote!{ #table_name::#column.eq(next_version), },
+            ),
+            None => (quote!{}, quote!{}),
+        };
+        let before_update = if self.hooks {
+            quote!{ <Self as ::abstract_orm::Hooks>::before_update(self)?; }
+        } else {
+            quote!{}
+        };
+        let validate = self.make_validate_call(quote!{self});
+        let writer = kind.writer(quote!{?});
+        let execute = kind.run(quote!{
+            ::diesel::update(#table_name::table #(.filter(#table_name::#pk_columns.eq(&self.#pk_columns)))* #version_filter)
+                .set((
+                    #(#table_name::#update_columns.eq(&self.#update_columns),)*
+                    #(#table_name::#updated_at.eq(::diesel::dsl::now),)*
+                    #version_set
+                ))
+        }, quote!{execute});
+        let body = match version_column {
+            Some((column, version_type)) => quote!{
+                let next_version: #version_type = self.#column + 1;
+                let updated_rows = #execute?;
+                if updated_rows == 0 {
+                    return Err(::abstract_orm::OrmError::StaleObject { entity: #original_type });
+                }
+                self.#column = next_version;
+                Ok(())
+            },
+            None => quote!{
+                #execute?;
+                Ok(())
+            },
+        };
+        quote!{
+            pub #asyncness fn #fn_ident(
+                &mut self,
+                conn: #conn_type,
+            ) -> Result<(), ::abstract_orm::OrmError> {
+                #before_update
+                #validate
+                #writer
+                #body
+            }
+        }
+    }
+
+    /// `delete`, removing the row of `self`.
+    fn make_delete_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let pk_columns = self.pk_columns();
+        let fn_ident = kind.fn_ident(&Ident::new("delete", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let execute = kind.run(quote!{
+            ::diesel::delete(#table_name::table #(.filter(#table_name::#pk_columns.eq(&self.#pk_columns)))*)
+        }, quote!{execute});
+        let writer = kind.writer(quote!{?});
+        let body = if self.hooks {
+            quote!{
+                <Self as ::abstract_orm::Hooks>::before_delete(self)?;
+                let deleted_rows = #execute?;
+                if deleted_rows > 0 {
+                    <Self as ::abstract_orm::Hooks>::after_delete(self)?;
+                }
+                Ok(())
+            }
+        } else {
+            quote!{
+                #execute?;
+                Ok(())
+            }
+        };
+        quote!{
+            pub #asyncness fn #fn_ident(
+                &self,
+                conn: #conn_type,
+            ) -> Result<(), ::abstract_orm::OrmError> {
+                #writer
+                #body
+            }
+        }
+    }
+
+    /// `soft_delete`, `restore` and `find_all_with_deleted` of an entity
+    /// with `#[orm(soft_delete = ...)]`.
+    fn make_soft_delete_fns(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let column = match &self.soft_delete {
+            Some(column) => column,
+            None => return quote!{},
+        };
+        let column_type = match &self.soft_delete_type {
+            Some(column_type) => column_type,
+            None => abort!(column.span(), "`{}` is not a field of `{}`", column, self.original_type),
+        };
+        let pk_columns = self.pk_columns();
+        let soft_delete_ident = kind.fn_ident(&Ident::new("soft_delete", Span::call_site()));
+        let restore_ident = kind.fn_ident(&Ident::new("restore", Span::call_site()));
+        let find_all_ident = kind.fn_ident(&Ident::new("find_all_with_deleted", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let soft_delete = kind.run(quote!{
+            ::diesel::update(#table_name::table #(.filter(#table_name::#pk_columns.eq(&self.#pk_columns)))*.filter(#table_name::#column.is_null()))
+                .set(#table_name::#column.eq(::diesel::dsl::now))
+        }, quote!{execute});
+        let restore = kind.run(quote!{
+            ::diesel::update(#table_name::table #(.filter(#table_name::#pk_columns.eq(&self.#pk_columns)))*)
+                .set(#table_name::#column.eq(None::<#column_type>))
+        }, quote!{execute});
+        let load = kind.run(quote!{
+            #table_name::table
+                .order_by(::abstract_orm::Order::with_primary_key(order))
+                .select(Self::as_select())
+        }, quote!{load});
+        let writer = kind.writer(quote!{?});
+        let reader = kind.reader(quote!{.unwrap()});
+        let (soft_delete_error, soft_delete_body) = if self.hooks {
+            (quote!{::abstract_orm::OrmError}, quote!{
+                <Self as ::abstract_orm::Hooks>::before_delete(self)?;
+                let deleted_rows = #soft_delete?;
+                if deleted_rows > 0 {
+                    <Self as ::abstract_orm::Hooks>::after_delete(self)?;
+                }
+            })
+        } else {
+            (quote!{::diesel::result::Error}, quote!{
+                #soft_delete?;
+            })
+        };
+        let soft_delete_doc = format!("Sets `{}` to the current time, the row is then left out by every query but `find_all_with_deleted`. An already soft deleted row keeps its time.", column);
+        let restore_doc = format!("Clears `{}` of a soft deleted row.", column);
+        quote!{
+            #[doc = #soft_delete_doc]
+            pub #asyncness fn #soft_delete_ident(
+                &self,
+                conn: #conn_type,
+            ) -> Result<(), #soft_delete_error> {
+                #writer
+                #soft_delete_body
+                Ok(())
+            }
+            #[doc = #restore_doc]
+            pub #asyncness fn #restore_ident(
+                &self,
+                conn: #conn_type,
+            ) -> Result<(), ::diesel::result::Error> {
+                #writer
+                #restore?;
+                Ok(())
+            }
+            pub #asyncness fn #find_all_ident(
+                order: Option<::abstract_orm::Order<Self>>,
+                conn: #conn_type,
+            )->Vec<Self>{
+                #reader
+                return #load
+                    .unwrap();
+            }
+        }
+    }
+
+    /// `SoftDelete` impl, the filter joins of other entities apply to this one.
+    fn make_soft_delete_impl(&self, table_name: &TokenStream2)->TokenStream2 {
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (filter_type, filter) = match &self.soft_delete {
+            Some(column) => (
+                quote!{::diesel::dsl::IsNull<#table_name::#column>},
+                quote!{#table_name::#column.is_null()},
+            ),
+            None => (
+                quote!{::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>},
+                quote!{::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true)},
+            ),
+        };
+        quote!{
+            impl #impl_generics ::abstract_orm::SoftDelete for #original_type #ty_generics #where_clause {
+                type NotDeleted = #filter_type;
+
+                fn not_deleted() -> Self::NotDeleted {
+                    return #filter;
+                }
+            }
+        }
+    }
+
+    /// Statements loading `table_name` with all its relations into the
+    /// `XWithAll` values of `ret_data`, keeping the rows passing `filter`
+    /// sorted by the `Option<Order>` expression `order`.
+    fn load_eager(&self, table_name: &TokenStream2, filter: TokenStream2, order: TokenStream2, kind: ConnKind)->TokenStream2 {
+        let Self {original_type, join_statements, rel_collect_types, ..} = self;
+        let ident_with_all = util::format_ident("{}WithAll", original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let join_stmts: Vec<TokenStream2> = join_statements.iter()
+            .filter(|(f_name, _)| self.is_eager_joined(f_name))
+            .map(|(_, join_stmt)| join_stmt.clone())
+            .collect();
+        let not_deleted = self.not_deleted_filter(table_name);
+        let load = kind.run(quote!{
+            #table_name::table
+                #(#join_stmts)*
+                #not_deleted
+                #filter
+                .order_by(::abstract_orm::Order::with_primary_key(#order))
+                .select((Self::as_select(), #(#rel_collect_types::as_select()),*))
+        }, quote!{load::<(Self, #(#rel_collect_types),*)>});
+        let scoped_loads = self.eager_scoped_loads(table_name, kind);
+        let sort_children: Vec<TokenStream2> = self.child_orders.keys()
+            .map(|f_name| self.sort_children(f_name, quote!{&mut data.#f_name}))
+            .collect();
+        let sort_loop = if !sort_children.is_empty() {
+            quote!{
+                for data in &mut ret_data {
+                    #(#sort_children)*
+                }
+            }
+        } else {
+            quote!{}
+        };
+        quote!{
+            let mut ret_data:Vec<#ident_with_all #ty_generics> = Vec::new();
+            let mut last_id = 0;
+        
+            let all_rows = #load?;
+        
+            
+            for query_row in all_rows {
+                let current_id = query_row.0.id;
+                if last_id == current_id {
+                    ret_data.last_mut().unwrap().insert_data(query_row);
+                }else {
+                    let data = #ident_with_all::new_builder(query_row);
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            #scoped_loads
+            #sort_loop
+        }
+    }
+
+    fn make_find_all_eager_fn(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let Self {original_type, join_statements, model_pk, ..} = self;
+        let ident_with_all = util::format_ident("{}WithAll", original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let join_stmts: Vec<TokenStream2> = join_statements.iter()
+            .filter(|(f_name, _)| self.is_eager_joined(f_name))
+            .map(|(_, join_stmt)| join_stmt.clone())
+            .collect();
+        println!("join statements {:?}", join_stmts.clone().into_iter().map(|t| format!("{}", t)).collect::<String>());
+        if !join_statements.is_empty() && model_pk.is_empty() {
+            let fn_ident = kind.fn_ident(&Ident::new("find_all_eager", Span::call_site()));
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(self.backend);
+            let load_eager = self.load_eager(table_name, quote!{}, quote!{order}, kind);
+            let reader = kind.reader(quote!{?});
+            quote!{
+                pub #asyncness fn #fn_ident(
+                    order: Option<::abstract_orm::Order<Self>>,
+                    conn: #conn_type
+                )->Result<Vec<#ident_with_all #ty_generics>, ::diesel::result::Error>{
+                    #reader
+                    #load_eager
+                
+                    Ok(ret_data)
+                }
+            }
+        }else {
+            quote!{}
+        }
+
+    }
+
+    /// `iter_all`, and `iter_all_with_x` and `iter_all_eager` when there are
+    /// relations, going over the table `batch_size` entities at a time in
+    /// `id` order. Each page of `XLazy` or `XWithAll` is the grouped load of
+    /// the next `batch_size` ids so a parent is never split between pages.
+    fn make_iter_fns(&self, table_name: &TokenStream2)->Vec<TokenStream2> {
+        if !self.model_pk.is_empty() {
+            return Vec::new();
+        }
+        let kind = ConnKind::Sync;
+        let ident_lazy = util::format_ident("{}Lazy", &self.original_type);
+        let ident_with_all = util::format_ident("{}WithAll", &self.original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let conn_type = kind.conn_type(self.backend);
+        let connection = quote!{<Self as ::abstract_orm::Entity>::Connection};
+        let not_deleted = self.not_deleted_filter(table_name);
+        let no_order = quote!{None::<::abstract_orm::Order<Self>>};
+        let filter = quote!{ .filter(#table_name::id.eq_any(&keys)) };
+        let load = kind.run(quote!{
+            #table_name::table
+                #not_deleted
+                .filter(#table_name::id.gt(after))
+                .order_by(#table_name::id)
+                .limit(batch_size as i64)
+                .select(Self::as_select())
+        }, quote!{load});
+        let load_keys = kind.run(quote!{
+            #table_name::table
+                #not_deleted
+                .filter(#table_name::id.gt(after))
+                .order_by(#table_name::id)
+                .limit(batch_size as i64)
+                .select(#table_name::id)
+        }, quote!{load::<i64>});
+        let mut output: Vec<TokenStream2> = vec![quote!{
+            pub fn iter_all(
+                batch_size: usize,
+                conn: #conn_type,
+            ) -> impl Iterator<Item = Result<Self, ::diesel::result::Error>> + '_ {
+                return ::abstract_orm::Batches::new(conn, batch_size, move |after: i64, conn: &mut #connection| {
+                    let data = #load?;
+                    let last_key = data.last().map(|data| data.id);
+                    return Ok(::abstract_orm::Page { keys: data.len(), last_key, data });
+                });
+            }
+        }];
+        for f_name in self.join_statements.keys() {
+            let fn_ident = util::format_ident("iter_all_with_{}", f_name);
+            let load_lazy = self.load_lazy_with(table_name, f_name, filter.clone(), no_order.clone(), kind);
+            output.push(quote!{
+                pub fn #fn_ident(
+                    batch_size: usize,
+                    conn: #conn_type,
+                ) -> impl Iterator<Item = Result<#ident_lazy #ty_generics, ::diesel::result::Error>> + '_ {
+                    return ::abstract_orm::Batches::new(conn, batch_size, move |after: i64, conn: &mut #connection| {
+                        let keys = #load_keys?;
+                        #load_lazy
+                        return Ok(::abstract_orm::Page { data: ret_data, last_key: keys.last().copied(), keys: keys.len() });
+                    });
+                }
+            });
+        }
+        if !self.join_statements.is_empty() {
+            let load_eager = self.load_eager(table_name, filter.clone(), no_order.clone(), kind);
+            output.push(quote!{
+                pub fn iter_all_eager(
+                    batch_size: usize,
+                    conn: #conn_type,
+                ) -> impl Iterator<Item = Result<#ident_with_all #ty_generics, ::diesel::result::Error>> + '_ {
+                    return ::abstract_orm::Batches::new(conn, batch_size, move |after: i64, conn: &mut #connection| {
+                        let keys = #load_keys?;
+                        #load_eager
+                        return Ok(::abstract_orm::Page { data: ret_data, last_key: keys.last().copied(), keys: keys.len() });
+                    });
+                }
+            });
+        }
+        output
+    }
+
+    /// Fills the scoped and `has_many_through` relations of the `XWithAll` in
+    /// `ret_data`, each with its own join restricted to the loaded ids as
+    /// their table is already in the `find_all_eager` join for another one.
+    fn eager_scoped_loads(&self, table_name: &TokenStream2, kind: ConnKind)->TokenStream2 {
+        let mut loads: Vec<TokenStream2> = Vec::new();
+        for (f_name, join_stmt) in &self.join_statements {
+            if self.is_eager_joined(f_name) {
+                continue;
+            }
+            let select_type = self.rel_types_map.get(f_name).unwrap();
+            let load = kind.run(quote!{
+                #table_name::table
+                    #join_stmt
+                    .filter(#table_name::id.eq_any(&ids))
+                    .select((#table_name::id, #select_type::as_select()))
+            }, quote!{load::<(i64, #select_type)>});
+            loads.push(quote!{
+                for (id, #f_name) in #load? {
+                    if let Some(related) = #f_name {
+                        ret_data[positions[&id]].#f_name.push(related);
+                    }
+                }
+            });
+        }
+        if loads.is_empty() {
+            return quote!{};
+        }
+        quote!{
+            let positions: ::std::collections::HashMap<i64, usize> = ret_data.iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            #(#loads)*
+        }
+    }
+
+    /// Stores the related value `f_name` of one joined row in the lazy `data`.
+    fn relation_data_assign(&self, f_name: &Ident)->TokenStream2 {
+        let set_fn_ident = util::format_ident("push_or_set_{}", f_name);
+        let select_type = self.rel_types_map.get(f_name).unwrap();
+        let many_rel = self.rel_with_join_map.get(f_name);
+        let table_name_i = self.get_table_name_ident().unwrap();
+        let join_id_field = util::format_ident("get_for_{}_id", &table_name_i);
+
+        if util::type_is_option(select_type) {
+            if many_rel.is_some() {
+                quote!{
+                    if let Some(val) = #f_name {
+                        data.#set_fn_ident(val.#join_id_field());
+                    }
+                }
+            }else {
+                quote!{
+                    if let Some(val) = #f_name {
+                        data.#set_fn_ident(val);
+                    }
+                }
+            }
+        }else {
+            quote!{
+                data.#set_fn_ident(#f_name);
+            }
+        }
+    }
+
+    /// Sorts the loaded children of `f_name` in every `XLazy` of `lazy`,
+    /// nothing when the relation has no `order_by` or only keeps ids.
+    fn lazy_sort_loop(&self, f_name: &Ident, lazy: TokenStream2)->TokenStream2 {
+        if self.rel_with_join_map.contains_key(f_name) {
+            return quote!{};
+        }
+        let sort = self.sort_children(f_name, quote!{children});
+        if sort.is_empty() {
+            return quote!{};
+        }
+        quote!{
+            for data in #lazy {
+                if let Some(children) = &mut data.#f_name {
+                    #sort
+                }
+            }
+        }
+    }
+
+    /// `Relation` impls of the markers generated by the lazy entity, each
+    /// runs the join of its `find_all_with_x` restricted to the loaded ids.
+    fn make_relation_loaders(&self, table_name: &TokenStream2)->TokenStream2 {
+        let Self {original_type, join_statements, model_pk, ..} = self;
+        if !model_pk.is_empty() || join_statements.is_empty() {
+            return quote!{};
+        }
+        let ident_lazy = util::format_ident("{}Lazy", original_type);
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut loaders: Vec<TokenStream2> = Vec::new();
+        for (f_name, join_stmt) in join_statements {
+            let marker_ident = util::relation_marker_ident(original_type, f_name);
+            let select_type = self.rel_types_map.get(f_name).unwrap();
+            let data_assign = self.relation_data_assign(f_name);
+            let sort_loop = self.lazy_sort_loop(f_name, quote!{lazy.iter_mut()});
+            let load = ConnKind::Sync.run(quote!{
+                #table_name::table
+                    #join_stmt
+                    .filter(#table_name::id.eq_any(ids))
+                    .select((#table_name::id, #select_type::as_select()))
+            }, quote!{load::<(i64, #select_type)>});
+            loaders.push(quote!{
+                impl #impl_generics ::abstract_orm::Relation<#original_type #ty_generics> for #marker_ident #where_clause {
+                    fn load_into(
+                        &self,
+                        lazy: &mut [#ident_lazy #ty_generics],
+                        conn: &mut <#original_type #ty_generics as ::abstract_orm::Entity>::Connection,
+                    ) -> Result<(), ::abstract_orm::OrmError> {
+                        let positions: ::std::collections::HashMap<i64, usize> = lazy.iter()
+                            .enumerate()
+                            .map(|(position, data)| (data.self_data.id, position))
+                            .collect();
+                        let ids: Vec<i64> = positions.keys().copied().collect();
+                        if ids.len() == 0 {
+                            return Ok(());
+                        }
+                        for (id, #f_name) in #load? {
+                            let data = &mut lazy[positions[&id]];
+                            #data_assign
+                        }
+                        #sort_loop
+                        return Ok(());
+                    }
+                }
+                impl #impl_generics ::abstract_orm::Relations<#original_type #ty_generics> for #marker_ident #where_clause {
+                    fn load_into(
+                        &self,
+                        lazy: &mut [#ident_lazy #ty_generics],
+                        conn: &mut <#original_type #ty_generics as ::abstract_orm::Entity>::Connection,
+                    ) -> Result<(), ::abstract_orm::OrmError> {
+                        return ::abstract_orm::Relation::load_into(self, lazy, conn);
+                    }
+                }
+            });
+        }
+        quote!{ #(#loaders)* }
+    }
+
+    /// `load_with` and `with_relations`, loading any set of relation markers
+    /// into `XLazy` values.
+    fn make_load_with_fns(&self)->TokenStream2 {
+        let Self {original_type, join_statements, model_pk, ..} = self;
+        if !model_pk.is_empty() || join_statements.is_empty() {
+            return quote!{};
+        }
+        let ident_lazy = util::format_ident("{}Lazy", original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let conn_type = ConnKind::Sync.conn_type(self.backend);
+        let reader = ConnKind::Sync.reader(quote!{?});
+        quote!{
+            pub fn load_with<R: ::abstract_orm::Relations<Self>>(
+                relations: R,
+                conn: #conn_type,
+            ) -> Result<Vec<#ident_lazy #ty_generics>, ::abstract_orm::OrmError> {
+                #reader
+                let mut lazy: Vec<#ident_lazy #ty_generics> = <Self as ::abstract_orm::Entity>::find_all(conn)?
+                    .into_iter()
+                    .map(#ident_lazy::init)
+                    .collect();
+                relations.load_into(&mut lazy, conn)?;
+                return Ok(lazy);
+            }
+            pub fn with_relations<R: ::abstract_orm::Relations<Self>>(
+                self,
+                relations: R,
+                conn: #conn_type,
+            ) -> Result<#ident_lazy #ty_generics, ::abstract_orm::OrmError> {
+                #reader
+                let mut lazy = [#ident_lazy::init(self)];
+                relations.load_into(&mut lazy, conn)?;
+                let [lazy] = lazy;
+                return Ok(lazy);
+            }
+        }
+    }
+
+    /// `find_all_as::<P>`, the rows selected as the projection `P`, only for
+    /// an `i64` key as the projections load their rows keyed by one.
+    fn make_find_all_as_fn(&self, table_name: &TokenStream2)->TokenStream2 {
+        if let Some(key_type) = self.model_pk.first().and_then(|key_column| self.model_pk_t.get(key_column)) {
+            if !util::type_is_i64(key_type) {
+                return quote!{};
+            }
+        }
+        let conn_type = ConnKind::Sync.conn_type(self.backend);
+        let reader = ConnKind::Sync.reader(quote!{?});
+        let key_column = self.model_pk.first().cloned().unwrap_or_else(|| Ident::new("id", Span::call_site()));
+        let not_deleted = self.not_deleted_projection_filter(table_name);
+        quote!{
+            pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+                conn: #conn_type,
+            ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+                #reader
+                let rows = P::load_keyed(#not_deleted, Box::new(#table_name::#key_column.nullable()), conn)?;
+                return Ok(rows.into_iter().map(|(_, data)| data).collect());
+            }
+        }
+    }
+
+    /// `find_all_with_x_as::<P, R>`, the relation `x` with the entity
+    /// selected as the projection `P` and the related entity as `R`.
+    /// Both are loaded with their own query and matched on the keys.
+    fn make_find_all_with_as(&self, table_name: &TokenStream2)->Vec<TokenStream2> {
+        let conn_type = ConnKind::Sync.conn_type(self.backend);
+        let reader = ConnKind::Sync.reader(quote!{?});
+        let mut output: Vec<TokenStream2> =  Vec::new();
+        if !self.model_pk.is_empty() {
+            return output;
+        }
+        let not_deleted = self.not_deleted_projection_filter(table_name);
+        for (f_name, (relation_kind, related_type)) in &self.relation_kinds {
+            let fn_ident = util::format_ident("find_all_with_{}_as", f_name);
+            let related_pk = quote!{::diesel::Table::primary_key(&#related_type::get_table_ref())};
+            let related_not_deleted = quote!{<#related_type as ::abstract_orm::SoftDelete>::not_deleted()};
+            let condition = self.relation_conditions.get(f_name).map(|condition| quote!{ .and(#condition) });
+            let scope = match relation_kind {
+                RelationKind::Through { through, .. } => {
+                    let through_scope = self.scope_filter(through);
+                    let scope = self.scope_filter(f_name);
+                    Some(quote!{ #through_scope #scope })
+                },
+                _ => self.scope_filter(f_name),
+            };
+            let (output_type, bounds, body) = match relation_kind {
+                RelationKind::ManyToOne { foreign_key, optional } => {
+                    let (output_type, assign) = if *optional {
+                        (quote!{(P, Option<R>)}, quote!{
+                            ret_data.push((data, related.get(&fk_value).cloned()));
+                        })
+                    } else {
+                        (quote!{(P, R)}, quote!{
+                            if let Some(related_data) = related.get(&fk_value) {
+                                ret_data.push((data, related_data.clone()));
+                            }
+                        })
+                    };
+                    (output_type.clone(), quote!{R: Clone}, quote!{
+                        let rows = P::load_keyed(#not_deleted, Box::new(#table_name::#foreign_key.nullable()), conn)?;
+                        let fk_values: Vec<i64> = rows.iter().filter_map(|(fk_value, _)| *fk_value).collect();
+                        let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                            Some(Box::new(#related_pk.eq_any(fk_values).and(#related_not_deleted).nullable())),
+                            Box::new(#related_pk.nullable()),
+                            conn,
+                        )?.into_iter().collect();
+                        let mut ret_data: Vec<#output_type> = Vec::new();
+                        for (fk_value, data) in rows {
+                            #assign
+                        }
+                        return Ok(ret_data);
+                    })
+                },
+                RelationKind::OneToMany => {
+                    (quote!{(P, Vec<R>)}, quote!{}, quote!{
+                        let rows = P::load_keyed(#not_deleted, Box::new(#table_name::id.nullable()), conn)?;
+                        let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+                        let foreign_key = <#related_type as ::diesel::associations::BelongsTo<Self>>::foreign_key_column();
+                        let related = R::load_keyed(
+                            Some(Box::new(foreign_key.eq_any(ids).and(#related_not_deleted)#condition.nullable())),
+                            Box::new(foreign_key.nullable()),
+                            conn,
+                        )?;
+                        let mut positions: ::std::collections::HashMap<Option<i64>, usize> = ::std::collections::HashMap::new();
+                        let mut ret_data: Vec<(P, Vec<R>)> = Vec::new();
+                        for (id, data) in rows {
+                            positions.insert(id, ret_data.len());
+                            ret_data.push((data, Vec::new()));
+                        }
+                        for (fk_value, related_data) in related {
+                            if let Some(position) = positions.get(&fk_value) {
+                                ret_data[*position].1.push(related_data);
+                            }
+                        }
+                        return Ok(ret_data);
+                    })
+                },
+                RelationKind::ManyToMany { join_type, .. } | RelationKind::Through { join_type, .. } => {
+                    let with_link = matches!(relation_kind, RelationKind::ManyToMany { with_link: true, .. });
+                    let (item_type, link_select, link_row, link_pattern, item) = if with_link {
+                        (
+                            quote!{(#join_type, R)},
+                            quote!{(foreign_key, #related_pk, #join_type::as_select())},
+                            quote!{(i64, i64, #join_type)},
+                            quote!{(id, related_id, link)},
+                            quote!{(link, related_data.clone())},
+                        )
+                    } else {
+                        (quote!{R}, quote!{(foreign_key, #related_pk)}, quote!{(i64, i64)}, quote!{(id, related_id)}, quote!{related_data.clone()})
+                    };
+                    let load_links = ConnKind::Sync.run(quote!{
+                        #join_type::get_table_ref()
+                            .inner_join(#related_type::get_table_ref())
+                            .filter(foreign_key.eq_any(ids))
+                            .filter(<#join_type as ::abstract_orm::SoftDelete>::not_deleted())
+                            .filter(#related_not_deleted)
+                            #scope
+                            .select(#link_select)
+                    }, quote!{load::<#link_row>});
+                    (quote!{(P, Vec<#item_type>)}, quote!{R: Clone}, quote!{
+                        let rows = P::load_keyed(#not_deleted, Box::new(#table_name::id.nullable()), conn)?;
+                        let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+                        let foreign_key = <#join_type as ::diesel::associations::BelongsTo<Self>>::foreign_key_column();
+                        let links = #load_links?;
+                        let related_ids: Vec<i64> = links.iter().map(|link| link.1).collect();
+                        let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                            Some(Box::new(#related_pk.eq_any(related_ids).nullable())),
+                            Box::new(#related_pk.nullable()),
+                            conn,
+                        )?.into_iter().collect();
+                        let mut positions: ::std::collections::HashMap<i64, usize> = ::std::collections::HashMap::new();
+                        let mut ret_data: Vec<(P, Vec<#item_type>)> = Vec::new();
+                        for (id, data) in rows {
+                            if let Some(id) = id {
+                                positions.insert(id, ret_data.len());
+                            }
+                            ret_data.push((data, Vec::new()));
+                        }
+                        for #link_pattern in links {
+                            if let (Some(position), Some(related_data)) = (positions.get(&id), related.get(&Some(related_id))) {
+                                ret_data[*position].1.push(#item);
+                            }
+                        }
+                        return Ok(ret_data);
+                    })
+                },
+            };
+            output.push(quote!{
+                pub fn #fn_ident<P, R>(
+                    conn: #conn_type,
+                ) -> Result<Vec<#output_type>, ::abstract_orm::OrmError>
+                where
+                    P: ::abstract_orm::Projection<Self>,
+                    R: ::abstract_orm::Projection<#related_type>,
+                    #bounds
+                {
+                    #reader
+                    #body
+                }
+            });
+        }
+        output
+    }
+
+    /// Statements loading `table_name` joined with the relation `f_name` into
+    /// the `XLazy` values of `ret_data`, keeping the rows passing `filter`
+    /// sorted by the `Option<Order>` expression `order`.
+    fn load_lazy_with(&self, table_name: &TokenStream2, f_name: &Ident, filter: TokenStream2, order: TokenStream2, kind: ConnKind)->TokenStream2 {
+        let ident_lazy = util::format_ident("{}Lazy", &self.original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let join_stmt = self.join_statements.get(f_name).unwrap();
+        let select_type = self.rel_types_map.get(f_name).unwrap();
+        let data_assign = self.relation_data_assign(f_name);
+        let not_deleted = self.not_deleted_filter(table_name);
+        let load = kind.run(quote!{
+            #table_name::table
+                #join_stmt
+                #not_deleted
+                #filter
+                .order_by(::abstract_orm::Order::with_primary_key(#order))
+                .select((Self::as_select(), #select_type::as_select()))
+        }, quote!{load::<(Self, #select_type)>});
+        let sort_loop = self.lazy_sort_loop(f_name, quote!{&mut ret_data});
+        quote!{
+            let mut ret_data:Vec<#ident_lazy #ty_generics> = Vec::new();
+            let mut last_id = 0;
+
+            let all_rows = #load?;
+
+            for (self_data, #f_name) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    #data_assign
+                }else {
+                    let mut data = #ident_lazy::init(self_data);
+                    #data_assign;
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            #sort_loop
+        }
+    }
+
+    fn make_find_all_with(&self, table_name: &TokenStream2, kind: ConnKind)->Vec<TokenStream2> {
+        let Self {original_type, join_statements, model_pk, ..} = self;
+        let ident_lazy = util::format_ident("{}Lazy", original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let mut output: Vec<TokenStream2> =  Vec::new();
+        if model_pk.is_empty() {
+            for f_name in join_statements.keys() {
+                let fn_ident = kind.fn_ident(&util::format_ident("find_all_with_{}", f_name));
+                let asyncness = kind.asyncness();
+                let conn_type = kind.conn_type(self.backend);
+                let load_lazy = self.load_lazy_with(table_name, f_name, quote!{}, quote!{order}, kind);
+                let reader = kind.reader(quote!{?});
+                output.push(quote!{
+                    pub #asyncness fn #fn_ident(
+                        order: Option<::abstract_orm::Order<Self>>,
+                        conn: #conn_type
+                    )->Result<Vec<#ident_lazy #ty_generics>, ::diesel::result::Error>{
+                        #reader
+                        #load_lazy
+                    
+                        Ok(ret_data)
+                    }
+                });
+            }
+        }
+
+        output
+    }
+
+    /// `find_by_x` for every `#[orm(unique)]` field and `find_all_by_x` for
+    /// every `#[orm(index)]` one, each with a `_with_y` variant per relation
+    /// loading it like `find_all_with_y`.
+    fn make_lookup_fns(&self, table_name: &TokenStream2, kind: ConnKind)->Vec<TokenStream2> {
+        let ident_lazy = util::format_ident("{}Lazy", &self.original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let not_deleted = self.not_deleted_filter(table_name);
+        let reader = kind.reader(quote!{?});
+        let mut output: Vec<TokenStream2> = Vec::new();
+        for (field_name, (type_name, unique)) in &self.lookups {
+            let param_type = util::lookup_type(type_name);
+            // `= NULL` matches no row, a `None` value looks for the null ones instead.
+            let filter = if util::type_is_option(type_name) {
+                quote!{ .filter(
+                    ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(#field_name.is_none())
+                        .and(#table_name::#field_name.is_null())
+                        .or(#table_name::#field_name.eq(#field_name))
+                ) }
+            } else {
+                quote!{ .filter(#table_name::#field_name.eq(#field_name)) }
+            };
+            let relations: Vec<&Ident> = if self.model_pk.is_empty() {
+                self.join_statements.keys().collect()
+            } else {
+                Vec::new()
+            };
+            if *unique {
+                let fn_ident = kind.fn_ident(&util::format_ident("find_by_{}", field_name));
+                let first = kind.run(quote!{
+                    #table_name::table
+                        #not_deleted
+                        #filter
+                }, quote!{first::<Self>});
+                output.push(quote!{
+                    pub #asyncness fn #fn_ident(
+                        #field_name: #param_type,
+                        conn: #conn_type,
+                    ) -> Result<Option<Self>, ::diesel::result::Error> {
+                        #reader
+                        return #first.optional();
+                    }
+                });
+                for f_name in relations {
+                    let fn_ident = kind.fn_ident(&Ident::new(&format!("find_by_{}_with_{}", field_name, f_name), Span::call_site()));
+                    let load_lazy = self.load_lazy_with(table_name, f_name, filter.clone(), quote!{None::<::abstract_orm::Order<Self>>}, kind);
+                    output.push(quote!{
+                        pub #asyncness fn #fn_ident(
+                            #field_name: #param_type,
+                            conn: #conn_type,
+                        ) -> Result<Option<#ident_lazy #ty_generics>, ::diesel::result::Error> {
+                            #reader
+                            #load_lazy
+                            return Ok(ret_data.pop());
+                        }
+                    });
+                }
+            } else {
+                let fn_ident = kind.fn_ident(&util::format_ident("find_all_by_{}", field_name));
+                let load = kind.run(quote!{
+                    #table_name::table
+                        #not_deleted
+                        #filter
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select())
+                }, quote!{load});
+                output.push(quote!{
+                    pub #asyncness fn #fn_ident(
+                        #field_name: #param_type,
+                        order: Option<::abstract_orm::Order<Self>>,
+                        conn: #conn_type,
+                    ) -> Result<Vec<Self>, ::diesel::result::Error> {
+                        #reader
+                        return #load;
+                    }
+                });
+                for f_name in relations {
+                    let fn_ident = kind.fn_ident(&Ident::new(&format!("find_all_by_{}_with_{}", field_name, f_name), Span::call_site()));
+                    let load_lazy = self.load_lazy_with(table_name, f_name, filter.clone(), quote!{order}, kind);
+                    output.push(quote!{
+                        pub #asyncness fn #fn_ident(
+                            #field_name: #param_type,
+                            order: Option<::abstract_orm::Order<Self>>,
+                            conn: #conn_type,
+                        ) -> Result<Vec<#ident_lazy #ty_generics>, ::diesel::result::Error> {
+                            #reader
+                            #load_lazy
+                            return Ok(ret_data);
+                        }
+                    });
+                }
+            }
+        }
+        output
+    }
+
+    /// `find_many` and `find_map` for an entity with a single column key,
+    /// and a `find_many_with_x` per relation for one keyed by `id`, binding
+    /// the keys `FIND_MANY_CHUNK_SIZE` at a time.
+    fn make_find_many_fns(&self, table_name: &TokenStream2, kind: ConnKind)->Vec<TokenStream2> {
+        if self.model_pk_t.len() > 1 {
+            return Vec::new();
+        }
+        let (key_type, key_field) = self.key_type_and_pattern();
+        let key_field = if self.model_pk_t.is_empty() { quote!{id} } else { key_field };
+        let ident_lazy = util::format_ident("{}Lazy", &self.original_type);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let find_many_ident = kind.fn_ident(&Ident::new("find_many", Span::call_site()));
+        let find_map_ident = kind.fn_ident(&Ident::new("find_map", Span::call_site()));
+        let asyncness = kind.asyncness();
+        let conn_type = kind.conn_type(self.backend);
+        let dot_await = kind.dot_await();
+        let not_deleted = self.not_deleted_filter(table_name);
+        let filter = quote!{ .filter(#table_name::#key_field.eq_any(chunk)) };
+        let no_order = quote!{None::<::abstract_orm::Order<Self>>};
+        let reader = kind.reader(quote!{?});
+        let load = kind.run(quote!{
+            #table_name::table
+                #not_deleted
+                #filter
+                .order_by(::abstract_orm::Order::with_primary_key(#no_order))
+                .select(Self::as_select())
+        }, quote!{load});
+        let mut output: Vec<TokenStream2> = vec![quote!{
+            pub #asyncness fn #find_many_ident(
+                keys: &[#key_type],
+                conn: #conn_type,
+            ) -> Result<Vec<Self>, ::diesel::result::Error> {
+                #reader
+                let mut ret_data: Vec<Self> = Vec::new();
+                for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                    ret_data.extend(#load?);
+                }
+                return Ok(ret_data);
+            }
+
+            pub #asyncness fn #find_map_ident(
+                keys: &[#key_type],
+                conn: #conn_type,
+            ) -> Result<::std::collections::HashMap<#key_type, Self>, ::diesel::result::Error> {
+                let found = Self::#find_many_ident(keys, conn)#dot_await?;
+                return Ok(found.into_iter().map(|data| (data.#key_field.clone(), data)).collect());
+            }
+        }];
+        if !self.model_pk.is_empty() {
+            return output;
+        }
+        for f_name in self.join_statements.keys() {
+            let fn_ident = kind.fn_ident(&util::format_ident("find_many_with_{}", f_name));
+            let load_lazy = self.load_lazy_with(table_name, f_name, filter.clone(), no_order.clone(), kind);
+            output.push(quote!{
+                pub #asyncness fn #fn_ident(
+                    keys: &[#key_type],
+                    conn: #conn_type,
+                ) -> Result<Vec<#ident_lazy #ty_generics>, ::diesel::result::Error> {
+                    #reader
+                    let mut all_data: Vec<#ident_lazy #ty_generics> = Vec::new();
+                    for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                        #load_lazy
+                        all_data.extend(ret_data);
+                    }
+                    return Ok(all_data);
+                }
+            });
+        }
+        output
+    }
+
+    /// Compile time checks of the relation attributes against the schema, each
+    /// call carries the span of the related type in the attribute so a missing
+    /// `joinable!`, `allow_tables_to_appear_in_same_query!` or `belongs_to` is
+    /// reported there instead of deep inside the generated joins.
+    fn make_relation_asserts(&self)->TokenStream2 {
+        let relation_asserts = &self.relation_asserts;
+        if relation_asserts.is_empty() {
+            return quote!{};
+        }
+        quote!{
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            fn __assert_relations() {
+                fn relation_needs_joinable<Left, Right>(_: Left, _: Right)
+                where Left: ::diesel::JoinTo<Right> {}
+                fn relation_needs_allow_tables_to_appear_in_same_query<Left, Right>(_: Left, _: Right)
+                where Left: ::diesel::query_source::TableNotEqual<Right>, Right: ::diesel::Table {}
+                fn relation_needs_belongs_to<Child, Parent>()
+                where Child: ::diesel::associations::BelongsTo<Parent> {}
+                #(#relation_asserts)*
+            }
+        }
+    }
+
+    pub fn build(&self)->TokenStream2 {
+        let Self {
+            original_type,
+            rel_fields_lazy_get,
+            ..
+            } = &self;
+
+        
+        if let Some((column, None)) = &self.version {
+            abort!(column.span(), "`{}` is not a field of `{}`", column, original_type);
+        }
+        if let Some (table_name) = &self.table_path() {
+            let mut crud_fns: Vec<TokenStream2> = Vec::new();
+            for kind in ConnKind::enabled() {
+                crud_fns.push(self.make_find_all_fn(table_name, kind));
+                crud_fns.push(self.make_insert_fn(table_name, kind));
+                crud_fns.push(self.make_update_fn(table_name, kind));
+                crud_fns.push(self.make_delete_fn(table_name, kind));
+                crud_fns.push(self.make_find_fn(table_name, kind));
+                crud_fns.push(self.make_soft_delete_fns(table_name, kind));
+                crud_fns.push(self.make_find_all_eager_fn(table_name, kind));
+                crud_fns.extend(self.make_find_all_with(table_name, kind));
+                crud_fns.extend(self.make_lookup_fns(table_name, kind));
+                crud_fns.extend(self.make_find_many_fns(table_name, kind));
+            }
+            crud_fns.extend(self.make_iter_fns(table_name));
+            crud_fns.push(self.make_find_all_as_fn(table_name));
+            crud_fns.extend(self.make_find_all_with_as(table_name));
+            let relation_asserts = self.make_relation_asserts();
+            let entity_impl = self.make_entity_impl(table_name);
+            let soft_delete_impl = self.make_soft_delete_impl(table_name);
+            let relation_impls = &self.relation_impls;
+            let relation_loaders = self.make_relation_loaders(table_name);
+            let load_with_fns = self.make_load_with_fns();
+            let diesel_imports = util::diesel_imports();
+            let schema_import = self.schema.as_ref().map(|schema| quote!{
+                #[allow(unused_imports)]
+                use #schema::*;
+            });
+            let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+            quote!{
+                const _: () = {
+                    #diesel_imports
+                    #schema_import
+                    impl #impl_generics #original_type #ty_generics #where_clause {
+                        pub fn get_table_ref() -> #table_name::table {
+                            return #table_name::table;
+                        }
+                        #(#crud_fns)*
+                        #(#rel_fields_lazy_get)*
+                        #load_with_fns
+                        #relation_asserts
+                    }
+                    #entity_impl
+                    #soft_delete_impl
+                    #(#relation_impls)*
+                    #relation_loaders
+                };
+            }
+        }else {
+            quote!{}
+        }
+    }
+}
\ No newline at end of file
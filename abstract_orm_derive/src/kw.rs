@@ -0,0 +1,3 @@
+syn::custom_keyword!(by);
+syn::custom_keyword!(through);
+syn::custom_keyword!(with_link);
@@ -10,25 +10,39 @@ struct TypeData {
 
 pub struct LazyEntityAST {
     original_type: Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
     rel_fields_lazy: Vec<syn::Field>,
     rel_default_assign_lazy: Vec<FieldValue>,
     field_type_map: BTreeMap<Ident,TypeData>,
+    derives: Vec<syn::Path>,
+    serde_attrs: Vec<syn::Attribute>,
 }
 
 impl LazyEntityAST {
-    pub fn new(original_type: &Ident) -> Self {
+    pub fn new(original_type: &Ident, vis: &syn::Visibility, generics: &syn::Generics) -> Self {
         Self {
             original_type: original_type.clone(),
+            vis: vis.clone(),
+            generics: generics.clone(),
             rel_fields_lazy: Vec::new(),
             rel_default_assign_lazy: Vec::new(),
             field_type_map: BTreeMap::new(),
+            derives: util::default_derives(&["Serialize"], &["Clone"]),
+            serde_attrs: Vec::new(),
         }
     }
+    pub fn set_derives(&mut self, derives: Vec<syn::Path>){
+        self.derives = derives;
+    }
+    pub fn push_serde_attr(&mut self, attr: &syn::Attribute){
+        self.serde_attrs.push(attr.clone());
+    }
     pub fn push_many_to_one(&mut self, field_name:&Ident, type_name:&Type){
         let gen_field_name = field_name.to_string().replace("_id", "");
         let gen_field_ident =
             syn::Ident::new(&gen_field_name, Span::call_site());
-        let sanitized_type = util::extract_type_from_option(&type_name);
+        let sanitized_type = util::extract_type_from_option(type_name);
         if util::type_is_option(type_name) {
             self.rel_fields_lazy.push(syn::parse_quote! { pub #gen_field_ident: #type_name });
         } else {
@@ -43,7 +57,7 @@ impl LazyEntityAST {
     }
     pub fn push_one_to_many(&mut self, field_name:&Ident, type_name:&Type){
 
-        if util::type_contains(&type_name, "To") {
+        if util::type_contains(type_name, "To") {
             let type_name_i: syn::Type = syn::parse_quote!{i64};
             self.rel_fields_lazy.push(syn::parse_quote! { pub #field_name: Option<Vec<#type_name_i>> });
             self.rel_default_assign_lazy.push(syn::parse_quote! { #field_name: None});
@@ -101,7 +115,6 @@ impl LazyEntityAST {
 
     fn make_push_or_set(&self)->Vec<TokenStream2> {
         let Self {
-            original_type,
             field_type_map,
             ..
         } = self;
@@ -128,34 +141,77 @@ impl LazyEntityAST {
                 });
             }
         }
-        return ret_val;
+        ret_val
+    }
+
+    /// Zero sized marker per relation with its `X::FIELD` constant, loaded
+    /// through the `Relation` impl the entity implementation generates.
+    fn make_relation_markers(&self)->TokenStream2 {
+        let Self {
+            original_type,
+            vis,
+            generics,
+            field_type_map,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let mut markers: Vec<TokenStream2> = Vec::new();
+        let mut consts: Vec<TokenStream2> = Vec::new();
+        for f_name in field_type_map.keys() {
+            let marker_ident = util::relation_marker_ident(original_type, f_name);
+            let const_ident = util::relation_const_ident(f_name);
+            let doc = format!("Marker of the `{}` relation of [`{}`].", f_name, original_type);
+            markers.push(quote!{
+                #[doc = #doc]
+                #[derive(Clone, Copy, Debug, Default)]
+                #vis struct #marker_ident;
+            });
+            consts.push(quote!{
+                pub const #const_ident: #marker_ident = #marker_ident;
+            });
+        }
+        quote!{
+            #(#markers)*
+            impl #impl_generics #original_type #ty_generics #where_clause {
+                #(#consts)*
+            }
+        }
     }
 
     pub fn build(self)->TokenStream2 {
         let setters = self.make_push_or_set();
+        let relation_markers = self.make_relation_markers();
         let Self {
             original_type,
+            vis,
+            generics,
             rel_fields_lazy,
             rel_default_assign_lazy,
+            derives,
+            serde_attrs,
             ..
             } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let ident_lazy = util::format_ident("{}Lazy", &original_type);
-        if rel_fields_lazy.len() == 0 {
+        if rel_fields_lazy.is_empty() {
             return quote!{};
         }
-        return quote!{
-            #[derive(Serialize, Clone)]
-            pub struct #ident_lazy {
-                #[serde(flatten)]
-                pub self_data: #original_type,
+        let derive_attrs = util::derive_attrs(&derives, &serde_attrs);
+        let flatten = if util::derives_serde(&derives) { quote!{#[serde(flatten)]} } else { quote!{} };
+        quote!{
+            #derive_attrs
+            #vis struct #ident_lazy #generics #where_clause {
+                #flatten
+                pub self_data: #original_type #ty_generics,
                 #(#rel_fields_lazy),*
             }
-            impl #ident_lazy {
-                pub fn init(self_data: #original_type) -> Self {
+            impl #impl_generics #ident_lazy #ty_generics #where_clause {
+                pub fn init(self_data: #original_type #ty_generics) -> Self {
                     Self {self_data, #(#rel_default_assign_lazy),*}
                 }
                 #(#setters)*
             }
-        };
+            #relation_markers
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,533 @@
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+extern crate proc_macro2;
+
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use proc_macro_error::{abort, proc_macro_error};
+use syn::{parse::{Parse, ParseStream}, parse_macro_input, punctuated::Punctuated, spanned::Spanned, DeriveInput, Ident, Token};
+use quote::ToTokens;
+use std::collections::BTreeMap;
+
+mod kw;
+mod util;
+mod connection;
+mod orm_attr;
+mod eager_entity;
+mod lazy_entity;
+mod entity_implementation;
+mod projection;
+mod polymorphic;
+mod validation;
+#[cfg(test)]
+mod tests;
+
+use eager_entity::{EagerEntityAST};
+use lazy_entity::{LazyEntityAST};
+use entity_implementation::{EntityImplAST};
+use projection::{ProjectionAST};
+use polymorphic::{PolymorphicAST};
+use validation::{ValidationAST};
+use orm_attr::{ChildOrder, OneToManyArg, OrmArg, OrmFieldArg, PolymorphicAttr, ValidateRule};
+
+#[allow(dead_code)]
+struct ManyToManyAttr {
+    field_name: syn::Ident,
+    eq_token: Token![=],
+    type_name: syn::Type,
+    by_token: Option<kw::by>,
+    join_type: Option<syn::Type>,
+}
+
+/// One argument of the `#[many_to_many(...)]` attribute, a relation, the
+/// `where = ...` condition on the related entities of the one before it or
+/// `with_link` to keep the join table rows next to them.
+enum ManyToManyArg {
+    Relation(Box<ManyToManyAttr>),
+    Where(syn::Expr),
+    WithLink(kw::with_link),
+}
+
+impl Parse for ManyToManyArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::with_link) {
+            return Ok(ManyToManyArg::WithLink(input.parse()?));
+        }
+        if input.peek(Token![where]) {
+            input.parse::<Token![where]>()?;
+            input.parse::<Token![=]>()?;
+            return Ok(ManyToManyArg::Where(input.parse()?));
+        }
+        Ok(ManyToManyArg::Relation(input.parse()?))
+    }
+}
+
+impl Parse for ManyToManyAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let field_name: Ident = input.parse()?;
+        let eq_token: Token![=] = input.parse()?;
+        let type_name:syn::Type = input.parse::<syn::Type>()?;
+        let by_token: Option<kw::by> = input.parse()?;
+        let join_type: Option<syn::Type> = if by_token.is_some() {
+            Some(input.parse::<syn::Type>()?)
+        }else {
+            None
+        };
+        Ok(ManyToManyAttr {
+            field_name,
+            eq_token,
+            type_name,
+            by_token,
+            join_type
+        })
+    }
+}
+
+struct HasManyThroughAttr {
+    field_name: syn::Ident,
+    type_name: syn::Type,
+    through: syn::Ident,
+}
+
+impl Parse for HasManyThroughAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let field_name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let type_name: syn::Type = input.parse()?;
+        input.parse::<kw::through>()?;
+        let through: Ident = input.parse()?;
+        Ok(HasManyThroughAttr {
+            field_name,
+            type_name,
+            through,
+        })
+    }
+}
+
+/// One argument of the `#[has_many_through(...)]` attribute, a relation or
+/// the `where = ...` condition on the related entities of the one before it.
+enum HasManyThroughArg {
+    Relation(Box<HasManyThroughAttr>),
+    Where(syn::Expr),
+}
+
+impl Parse for HasManyThroughArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![where]) {
+            input.parse::<Token![where]>()?;
+            input.parse::<Token![=]>()?;
+            return Ok(HasManyThroughArg::Where(input.parse()?));
+        }
+        Ok(HasManyThroughArg::Relation(input.parse()?))
+    }
+}
+
+#[proc_macro_derive(Joinable, attributes(many_to_one, one_to_many, many_to_many, has_many_through, polymorphic, with_guard, orm, validate))]
+#[proc_macro_error]
+pub fn with_join(input: TokenStream) -> TokenStream {
+    // Parse the string representation
+    let ast: DeriveInput = parse_macro_input!(input);
+    return expand_joinable(ast).into();
+}
+
+fn expand_joinable(mut ast: DeriveInput) -> TokenStream2 {
+    let original_type = ast.ident.clone();
+    let vis = ast.vis.clone();
+    let generics = ast.generics.clone();
+    println!("starting work on {}", original_type);
+    match &mut ast.data {
+        syn::Data::Struct(ref mut struct_data) => {
+            let mut eager_entity =  EagerEntityAST::new(&original_type, &vis, &generics);
+            let mut lazy_entity =  LazyEntityAST::new(&original_type, &vis, &generics);
+            let mut entity_impl =  EntityImplAST::new(&original_type, &generics);
+            let mut projections =  ProjectionAST::new(&original_type, &vis, &generics);
+            let mut validations =  ValidationAST::new(&original_type, &generics);
+            let mut polymorphics =  PolymorphicAST::new(&original_type, &vis, &generics);
+
+            let mut save_object_fields: Vec<syn::Field> = Vec::new();
+            let mut save_object_derives = util::default_derives(&["Serialize", "Deserialize"], &[]);
+            let mut borrowed_insert = false;
+            let mut save_object_serde_attrs: Vec<syn::Attribute> = Vec::new();
+            // `orm` options come first as the relation attributes depend on them
+            let (orm_attrs, other_attrs): (Vec<syn::Attribute>, Vec<syn::Attribute>) = ast.attrs
+                .into_iter()
+                .partition(|attr| attr.meta.path().is_ident("orm"));
+            for struct_attr in orm_attrs.into_iter().chain(other_attrs) {
+                let is_one_to_many = struct_attr.meta.path().is_ident("one_to_many");
+                let is_many_to_many = struct_attr.meta.path().is_ident("many_to_many");
+                let is_with_guard = struct_attr.meta.path().is_ident("with_guard");
+                if is_one_to_many {
+                    let mut relations: Vec<(Ident, syn::Type, Option<ChildOrder>, Option<syn::Expr>)> = Vec::new();
+                    for arg in orm_attr::parse_orm_args::<OneToManyArg>(&struct_attr) {
+                        match arg {
+                            OneToManyArg::Relation(ident, type_name) => relations.push((ident, *type_name, None, None)),
+                            OneToManyArg::OrderBy(order) => match relations.last_mut() {
+                                Some((_, _, relation_order, _)) => *relation_order = Some(order),
+                                None => abort!(order.field.span(), "`order_by` has to follow a relation"),
+                            },
+                            OneToManyArg::Where(condition) => match relations.last_mut() {
+                                Some((_, _, _, relation_condition)) => add_condition(relation_condition, *condition),
+                                None => abort!(condition.span(), "`where` has to follow a relation"),
+                            },
+                            OneToManyArg::Polymorphic(key) => match relations.last_mut() {
+                                Some((_, type_name, _, relation_condition)) => add_condition(relation_condition, syn::parse_quote!{
+                                    <#type_name as ::abstract_orm::Polymorphic<#original_type>>::of_type()
+                                }),
+                                None => abort!(key.span(), "`polymorphic` has to follow a relation"),
+                            },
+                        }
+                    }
+
+                    for (ident, type_name, order, condition) in relations {
+                        if condition.is_some() {
+                            eager_entity.push_scoped(ident.clone(), type_name.clone());
+                        } else {
+                            eager_entity.push_one_to_many(ident.clone(), type_name.clone());
+                        }
+                        lazy_entity.push_one_to_many(&ident, &type_name);
+                        entity_impl.push_one_to_many(&ident, &type_name, order, condition);
+                    }
+                }
+                if is_many_to_many {
+                    let arguments = struct_attr
+                    .meta
+                    .require_list()
+                    .expect("can not parse many_to_many")
+                    .parse_args_with(Punctuated::<ManyToManyArg, Token![,]>::parse_terminated)
+                    .expect("error parsing many_to_many as type parameters");
+
+                    let mut relations: Vec<(ManyToManyAttr, Option<syn::Expr>, bool)> = Vec::new();
+                    for arg in arguments {
+                        match arg {
+                            ManyToManyArg::Relation(relation) => relations.push((*relation, None, false)),
+                            ManyToManyArg::Where(condition) => match relations.last_mut() {
+                                Some((_, relation_condition, _)) => *relation_condition = Some(condition),
+                                None => abort!(condition.span(), "`where` has to follow a relation"),
+                            },
+                            ManyToManyArg::WithLink(key) => match relations.last_mut() {
+                                Some((_, _, with_link)) => *with_link = true,
+                                None => abort!(key.span(), "`with_link` has to follow a relation"),
+                            },
+                        }
+                    }
+
+                    for (arg, condition, with_link) in relations {
+                        let ManyToManyAttr {field_name, type_name, join_type, ..} = arg;
+
+                        let join_type_ = if let Some(exist_join_type) = join_type {
+                            exist_join_type
+                        } else {
+                            let main_t = ast.ident.to_string();
+                            let mut type_str = format!("{}", quote!(#type_name));
+                            type_str = type_str.replace(&main_t, "");
+                            let dest_t = format!("{}To{}", main_t, type_str);
+                            syn::parse2::<syn::Type>(dest_t.parse().unwrap()).expect("can not find model type")
+                        };
+                        if with_link {
+                            let link_type: syn::Type = syn::parse_quote!{ (#join_type_, #type_name) };
+                            lazy_entity.push_many_to_many(&field_name, &link_type);
+                            eager_entity.push_scoped(field_name.clone(), link_type);
+                        } else {
+                            lazy_entity.push_many_to_many(&field_name.clone(), &type_name.clone());
+                            if condition.is_some() {
+                                eager_entity.push_scoped(field_name.clone(), type_name.clone());
+                            } else {
+                                eager_entity.push_many_to_many(&field_name.clone(), &type_name.clone(), &join_type_.clone());
+                            }
+                        }
+                        entity_impl.push_many_to_many(&field_name.clone(), &type_name.clone(), &join_type_.clone(), condition, with_link);
+                    }
+                }
+                if struct_attr.meta.path().is_ident("has_many_through") {
+                    let mut relations: Vec<(HasManyThroughAttr, Option<syn::Expr>)> = Vec::new();
+                    for arg in orm_attr::parse_orm_args::<HasManyThroughArg>(&struct_attr) {
+                        match arg {
+                            HasManyThroughArg::Relation(relation) => relations.push((*relation, None)),
+                            HasManyThroughArg::Where(condition) => match relations.last_mut() {
+                                Some((_, relation_condition)) => add_condition(relation_condition, condition),
+                                None => abort!(condition.span(), "`where` has to follow a relation"),
+                            },
+                        }
+                    }
+
+                    for (HasManyThroughAttr {field_name, type_name, through}, condition) in relations {
+                        lazy_entity.push_many_to_many(&field_name, &type_name);
+                        eager_entity.push_scoped(field_name.clone(), type_name.clone());
+                        entity_impl.push_has_many_through(&field_name, &type_name, &through, condition);
+                    }
+                }
+                if struct_attr.meta.path().is_ident("polymorphic") {
+                    let PolymorphicAttr { name, targets } = struct_attr
+                        .parse_args::<PolymorphicAttr>()
+                        .unwrap_or_else(|err| abort!(err.span(), "{}", err));
+                    polymorphics.push_association(name, targets);
+                }
+                if is_with_guard {
+                    let query_path = struct_attr
+                    .meta
+                    .require_list()
+                    .expect("can not parse with_guard")
+                    .parse_args_with(Punctuated::<syn::Ident, Token![.]>::parse_terminated)
+                    .expect("error parsing with_guard as type parameters");
+
+                    println!("query path guards: {:?}", query_path);
+                }
+                if struct_attr.meta.path().is_ident("orm") {
+                    for arg in orm_attr::parse_orm_args::<OrmArg>(&struct_attr) {
+                        match arg {
+                            OrmArg::Schema(schema) => entity_impl.set_schema(schema),
+                            OrmArg::Backend(backend) => entity_impl.set_backend(backend),
+                            OrmArg::NewDerive(derives) => save_object_derives = derives,
+                            OrmArg::LazyDerive(derives) => {
+                                polymorphics.set_derives(derives.clone());
+                                lazy_entity.set_derives(derives);
+                            },
+                            OrmArg::WithAllDerive(derives) => eager_entity.set_derives(derives),
+                            OrmArg::BorrowedInsert => {
+                                borrowed_insert = true;
+                                entity_impl.set_borrowed_insert();
+                            },
+                            OrmArg::Projection(projection_ident, field_names) => projections.push_projection(projection_ident, field_names),
+                            OrmArg::SoftDelete(column) => entity_impl.set_soft_delete(column),
+                            OrmArg::Timestamps => {
+                                entity_impl.set_created_at(Ident::new("created_at", Span::call_site()));
+                                entity_impl.set_updated_at(Ident::new("updated_at", Span::call_site()));
+                            },
+                            OrmArg::CreatedAt(column) => entity_impl.set_created_at(column),
+                            OrmArg::UpdatedAt(column) => entity_impl.set_updated_at(column),
+                            OrmArg::Version(column) => entity_impl.set_version(column),
+                            OrmArg::Hooks => entity_impl.set_hooks(),
+                        }
+                    }
+                }
+                if struct_attr.meta.path().is_ident("serde") {
+                    lazy_entity.push_serde_attr(&struct_attr);
+                    eager_entity.push_serde_attr(&struct_attr);
+                    save_object_serde_attrs.push(struct_attr.clone());
+                }
+                if struct_attr.meta.path().is_ident("diesel"){
+                    if let Ok(arguments) = struct_attr.meta.require_list() {
+                        entity_impl.parse_diesel_attr(arguments);
+                    }
+                }
+            }
+            if let syn::Fields::Named(fields) = &mut struct_data.fields {
+                fields.named.clone().into_iter().for_each(|f| {
+                    if let Some(field_name) = f.ident.clone() {
+                        let mut field_data = f.clone();
+                        projections.push_field(&field_name, &f);
+                        polymorphics.push_field(&field_name);
+                        let mut field_args: Vec<OrmFieldArg> = Vec::new();
+                        for attr in f.attrs {
+                            if attr.meta.path().is_ident("orm") {
+                                field_args.extend(orm_attr::parse_orm_args::<OrmFieldArg>(&attr));
+                            }
+                            if attr.meta.path().is_ident("validate") {
+                                validations.push_field(&field_name, &f.ty, orm_attr::parse_orm_args::<ValidateRule>(&attr));
+                            }
+                            if attr.meta.path().is_ident("many_to_one") {
+                                let argument = attr
+                                    .meta
+                                    .require_list()
+                                    .expect("error parsing macro many_to_one parameters")
+                                    .parse_args::<syn::Type>()
+                                    .expect("error parsing macro type");
+
+                                eager_entity.push_many_to_one(&field_name, argument.clone());
+                                lazy_entity.push_many_to_one(&field_name, &argument);
+                                entity_impl.push_many_to_one(&field_name, &argument);
+                            }
+                        }
+                        for arg in &field_args {
+                            match arg {
+                                OrmFieldArg::Unique => entity_impl.push_lookup(&field_name, &f.ty, true),
+                                OrmFieldArg::Index => entity_impl.push_lookup(&field_name, &f.ty, false),
+                                _ => {},
+                            }
+                        }
+                        entity_impl.search_pk_type(&field_name, &f.ty);
+                        entity_impl.search_version_type(&field_name, &f.ty);
+                        entity_impl.search_soft_delete_type(&field_name, &f.ty);
+                        let skip_insert = field_args.iter().any(|arg| matches!(arg, OrmFieldArg::SkipInsert))
+                            || entity_impl.is_timestamp(&field_name)
+                            || entity_impl.is_version(&field_name);
+                        if !skip_insert {
+                            entity_impl.push_update_column(&field_name);
+                        }
+                        if !skip_insert && !entity_impl.is_single_pk(&field_name) {
+                            let with_serde = util::derives_serde(&save_object_derives);
+                            field_data.attrs.retain(|attr| {
+                                let path = attr.meta.path();
+                                path.is_ident("diesel") || path.is_ident("doc") || path.is_ident("cfg") || (with_serde && path.is_ident("serde"))
+                            });
+                            if borrowed_insert {
+                                field_data.ty = util::borrowed_type(&field_data.ty);
+                            }
+                            let has_default = field_args.iter().any(|arg| matches!(arg, OrmFieldArg::Default));
+                            if has_default && !util::type_is_option(&field_data.ty) {
+                                field_data.ty = util::make_type_option(&field_data.ty);
+                            }
+                            validations.set_new_type(&field_name, &field_data.ty);
+                            save_object_fields.push(field_data);
+                        }
+                    }
+                });
+            }
+
+            let eager_entity_ast = eager_entity.build();
+
+            let lazy_entity_ast = lazy_entity.build();
+
+            if validations.has_rules() {
+                entity_impl.set_validated();
+            }
+            let entity_impl_ast = entity_impl.build();
+
+            let projections_ast = projections.build(entity_impl.table_path(), entity_impl.backend());
+
+            let polymorphics_ast = polymorphics.build(entity_impl.table_path(), entity_impl.backend());
+
+            let ident_save = util::format_ident("New{}", &ast.ident);
+
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let get_for = if original_type.to_string().contains("To") {
+                let pk1 = save_object_fields[0].ident.clone().unwrap();
+                let pk2 = save_object_fields[1].ident.clone().unwrap();
+                let get_for_pk1 = util::format_ident("get_for_{}", &pk1);
+                let get_for_pk2 = util::format_ident("get_for_{}", &pk2);
+                quote!{
+                    impl #impl_generics #original_type #ty_generics #where_clause {
+                        pub fn #get_for_pk1(&self) -> i64 {
+                            self.#pk2
+                        }
+                        pub fn #get_for_pk2(&self) -> i64 {
+                            self.#pk1
+                        }
+                    }
+                }
+            }else {
+                quote!{}
+            };
+
+            let table_ref_quote = if let Some(table_name_) = &entity_impl.table_path() {
+                let save_generics = util::insert_generics(&generics, borrowed_insert);
+                let save_where_clause = &save_generics.where_clause;
+                let derive_attrs = util::derive_attrs(&save_object_derives, &save_object_serde_attrs);
+                quote!{
+                    #[derive(::diesel::Insertable)]
+                    #derive_attrs
+                    #[diesel(table_name = #table_name_)]
+                    #vis struct #ident_save #save_generics #save_where_clause {
+                        #(#save_object_fields),*
+                    }
+                }
+            } else {
+                println!("no table name was found");
+                quote!{}
+            };
+
+            let validations_ast = if entity_impl.table_path().is_some() {
+                validations.build(borrowed_insert)
+            } else {
+                quote!{}
+            };
+
+            let ret_value = quote! {
+                #table_ref_quote
+                #validations_ast
+                #eager_entity_ast
+                #entity_impl_ast
+                #projections_ast
+                #polymorphics_ast
+                #lazy_entity_ast
+                #get_for
+            };
+            
+            println!("macro {}", ret_value);
+            ret_value
+        }
+        _ => panic!("Jinable has to be used with structs"),
+    }
+}
+
+/// Adds `condition` to the `where` of a relation with `.and(...)`.
+fn add_condition(relation_condition: &mut Option<syn::Expr>, condition: syn::Expr) {
+    *relation_condition = match relation_condition.take() {
+        Some(existing) => Some(syn::parse_quote!{ #existing.and(#condition) }),
+        None => Some(condition),
+    };
+}
+
+#[allow(dead_code)]
+struct FetchTree {
+    model: syn::Type,
+    ident: syn::Ident,
+    load: Vec<syn::Ident>
+}
+
+impl FetchTree {
+    fn new(ident:syn::Ident,model:syn::Type)-> Self {
+        Self {
+            model,
+            ident,
+            load: Vec::new()
+        }
+    }
+}
+
+#[proc_macro]
+pub fn lazy_block(input: TokenStream) -> TokenStream {
+    let block_statements = parse_macro_input!(input with syn::Block::parse_within);
+    let mut model_assignments: BTreeMap<syn::Ident, FetchTree> = BTreeMap::new();
+    
+    for statement in &block_statements {
+        match statement {
+            syn::Stmt::Local(let_stmt) => {
+                match &let_stmt.pat {
+                    syn::Pat::Ident(_) => {
+                        println!("let with no type assign");
+                        if let Some(init) = &let_stmt.init {
+                            if let syn::Expr::Call(ref call_expr) = *init.expr {
+                                println!("init Call expr");
+                                if let syn::Expr::Path(ref path_expr) = *call_expr.func {
+                                    let new_path = &mut path_expr.path.segments.clone();
+                                    if let Some(method) = new_path.last() {
+                                        if method.ident == "find" {
+                                            println!("init path call with find method");
+                                            new_path.pop();
+                                            new_path.pop_punct();
+                                        }
+                                    }
+                                    
+                                    println!("init path call from {}", quote!{#new_path});
+                                    let new_path_to_stream = new_path.to_token_stream();
+                                    let _generated_type:syn::Type = syn::parse2(new_path_to_stream).expect("can not find model type");
+                                }
+                            }
+                        }
+                    },
+                    syn::Pat::Type(pat_type) => {
+                        if let syn::Pat::Ident(ref ident_path) = *pat_type.pat {
+                            println!("let with type assign");
+                            model_assignments.insert(ident_path.ident.clone(), FetchTree::new(ident_path.ident.clone(), *pat_type.ty.clone()));
+                        }
+                    },
+                    _ => {}
+                }
+            },
+            syn::Stmt::Expr(_expr_stmt, _) => {
+                {}
+            }
+            _=>{}
+        }
+    }
+    
+    let ret_value = quote!{
+        #(#block_statements)*
+    };
+    println!("lazy block macro {}", ret_value);
+    ret_value.into()
+}
\ No newline at end of file
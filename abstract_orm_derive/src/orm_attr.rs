@@ -0,0 +1,247 @@
+use proc_macro_error::abort;
+use crate::connection::Backend;
+use syn::{parse::{Parse, ParseStream}, punctuated::Punctuated, Ident, LitStr, Token};
+
+/// One argument of the `#[orm(...)]` attribute.
+pub enum OrmArg {
+    /// `schema = "db::schema"`, module holding the diesel `table!` definitions
+    Schema(syn::Path),
+    /// `backend = mysql | postgres | sqlite`, connection the generated methods take
+    Backend(Backend),
+    /// `new_derive(...)`, derives of the generated `NewX` insertable
+    NewDerive(Vec<syn::Path>),
+    /// `lazy_derive(...)`, derives of the generated `XLazy` and `#[polymorphic(...)]` enums
+    LazyDerive(Vec<syn::Path>),
+    /// `with_all_derive(...)`, derives of the generated `XWithAll`
+    WithAllDerive(Vec<syn::Path>),
+    /// `borrowed_insert`, `NewX<'a>` borrows its `String` and `Vec<u8>` fields
+    BorrowedInsert,
+    /// `projection(XSummary: id, name)`, a struct selecting only the listed columns
+    Projection(Ident, Vec<Ident>),
+    /// `soft_delete = deleted_at`, nullable column marking a row as deleted instead of removing it
+    SoftDelete(Ident),
+    /// `timestamps`, the `created_at` and `updated_at` columns are managed by the generated code
+    Timestamps,
+    /// `created_at = column`, filled with the current time on insert
+    CreatedAt(Ident),
+    /// `updated_at = column`, filled with the current time on insert and update
+    UpdatedAt(Ident),
+    /// `version = lock_version`, integer column checked and incremented by `update`
+    Version(Ident),
+    /// `hooks`, the entity implements `abstract_orm::Hooks` and the CRUD methods call it
+    Hooks,
+}
+
+/// One argument of the `#[orm(...)]` attribute on a field.
+pub enum OrmFieldArg {
+    /// `skip_insert`, the column is filled by the database and left out of `NewX`
+    SkipInsert,
+    /// `default`, the column has a database default and is an `Option` in `NewX`
+    Default,
+    /// `unique`, generates `find_by_x` returning at most one entity
+    Unique,
+    /// `index`, generates `find_all_by_x`
+    Index,
+}
+
+/// One argument of the `#[one_to_many(...)]` attribute.
+pub enum OneToManyArg {
+    /// `posts = Post`, the entities of `Post` pointing at this one
+    Relation(Ident, Box<syn::Type>),
+    /// `order_by = created_at desc`, order of the children of the relation before it
+    OrderBy(ChildOrder),
+    /// `where = posts::published.eq(true)`, condition on the children of the relation before it
+    Where(Box<syn::Expr>),
+    /// `polymorphic`, the relation before it is the `#[polymorphic(...)]` association of the children
+    Polymorphic(Ident),
+}
+
+/// `#[polymorphic(commentable, types(Post, Photo))]`, an association stored in
+/// the `commentable_type` and `commentable_id` columns.
+pub struct PolymorphicAttr {
+    pub name: Ident,
+    pub targets: Vec<syn::Type>,
+}
+
+/// Field of the related entity its children are sorted by, `asc` by default.
+pub struct ChildOrder {
+    pub field: Ident,
+    pub descending: bool,
+}
+
+/// One rule of the `#[validate(...)]` attribute on a field.
+pub enum ValidateRule {
+    /// `length(min = 1, max = 255)`, number of characters of a string
+    Length { min: Option<syn::Expr>, max: Option<syn::Expr> },
+    /// `email`, the string looks like an email address
+    Email,
+    /// `range(min = 0, max = 100)`, bounds of a number, both included
+    Range { min: Option<syn::Expr>, max: Option<syn::Expr> },
+}
+
+impl Parse for OrmArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        match key.to_string().as_str() {
+            "schema" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                Ok(OrmArg::Schema(value.parse()?))
+            },
+            "backend" => {
+                input.parse::<Token![=]>()?;
+                let value: Ident = input.parse()?;
+                let backend = Backend::from_ident(&value)
+                    .ok_or_else(|| syn::Error::new(value.span(), "expected one of `mysql`, `postgres`, `sqlite`"))?;
+                Ok(OrmArg::Backend(backend))
+            },
+            "new_derive" => Ok(OrmArg::NewDerive(parse_path_list(input)?)),
+            "lazy_derive" => Ok(OrmArg::LazyDerive(parse_path_list(input)?)),
+            "with_all_derive" => Ok(OrmArg::WithAllDerive(parse_path_list(input)?)),
+            "borrowed_insert" => Ok(OrmArg::BorrowedInsert),
+            "projection" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let projection_ident: Ident = content.parse()?;
+                content.parse::<Token![:]>()?;
+                let fields = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                if fields.is_empty() {
+                    return Err(syn::Error::new(projection_ident.span(), "a projection needs at least one field"));
+                }
+                Ok(OrmArg::Projection(projection_ident, fields.into_iter().collect()))
+            },
+            "soft_delete" => {
+                input.parse::<Token![=]>()?;
+                Ok(OrmArg::SoftDelete(input.parse()?))
+            },
+            "timestamps" => Ok(OrmArg::Timestamps),
+            "created_at" => {
+                input.parse::<Token![=]>()?;
+                Ok(OrmArg::CreatedAt(input.parse()?))
+            },
+            "updated_at" => {
+                input.parse::<Token![=]>()?;
+                Ok(OrmArg::UpdatedAt(input.parse()?))
+            },
+            "hooks" => Ok(OrmArg::Hooks),
+            "version" => {
+                input.parse::<Token![=]>()?;
+                Ok(OrmArg::Version(input.parse()?))
+            },
+            _ => Err(syn::Error::new(key.span(), format!("unknown orm argument `{}`", key))),
+        }
+    }
+}
+
+impl Parse for OrmFieldArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        match key.to_string().as_str() {
+            "skip_insert" => Ok(OrmFieldArg::SkipInsert),
+            "default" => Ok(OrmFieldArg::Default),
+            "unique" => Ok(OrmFieldArg::Unique),
+            "index" => Ok(OrmFieldArg::Index),
+            _ => Err(syn::Error::new(key.span(), format!("unknown orm field argument `{}`", key))),
+        }
+    }
+}
+
+impl Parse for OneToManyArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![where]) {
+            input.parse::<Token![where]>()?;
+            input.parse::<Token![=]>()?;
+            return Ok(OneToManyArg::Where(input.parse()?));
+        }
+        let key: Ident = input.parse()?;
+        if key == "polymorphic" && !input.peek(Token![=]) {
+            return Ok(OneToManyArg::Polymorphic(key));
+        }
+        input.parse::<Token![=]>()?;
+        if key != "order_by" {
+            return Ok(OneToManyArg::Relation(key, input.parse()?));
+        }
+        let field: Ident = input.parse()?;
+        let mut descending = false;
+        if input.peek(Ident) {
+            let direction: Ident = input.parse()?;
+            descending = match direction.to_string().as_str() {
+                "asc" => false,
+                "desc" => true,
+                _ => return Err(syn::Error::new(direction.span(), "expected `asc` or `desc`")),
+            };
+        }
+        Ok(OneToManyArg::OrderBy(ChildOrder { field, descending }))
+    }
+}
+
+impl Parse for PolymorphicAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let key: Ident = input.parse()?;
+        if key != "types" {
+            return Err(syn::Error::new(key.span(), "expected `types(...)`"));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let targets = Punctuated::<syn::Type, Token![,]>::parse_terminated(&content)?;
+        Ok(PolymorphicAttr { name, targets: targets.into_iter().collect() })
+    }
+}
+
+impl Parse for ValidateRule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        match key.to_string().as_str() {
+            "email" => Ok(ValidateRule::Email),
+            "length" => {
+                let (min, max) = parse_bounds(&key, input)?;
+                Ok(ValidateRule::Length { min, max })
+            },
+            "range" => {
+                let (min, max) = parse_bounds(&key, input)?;
+                Ok(ValidateRule::Range { min, max })
+            },
+            _ => Err(syn::Error::new(key.span(), format!("unknown validate rule `{}`", key))),
+        }
+    }
+}
+
+/// `(min = .., max = ..)` of a `length` or `range` rule, at least one of them.
+fn parse_bounds(rule: &Ident, input: ParseStream) -> syn::Result<(Option<syn::Expr>, Option<syn::Expr>)> {
+    let content;
+    syn::parenthesized!(content in input);
+    let bounds = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(&content)?;
+    let mut min = None;
+    let mut max = None;
+    for bound in bounds {
+        if bound.path.is_ident("min") {
+            min = Some(bound.value);
+        } else if bound.path.is_ident("max") {
+            max = Some(bound.value);
+        } else {
+            return Err(syn::Error::new_spanned(&bound.path, "expected `min` or `max`"));
+        }
+    }
+    if min.is_none() && max.is_none() {
+        return Err(syn::Error::new(rule.span(), format!("`{}` needs a `min` or a `max`", rule)));
+    }
+    Ok((min, max))
+}
+
+fn parse_path_list(input: ParseStream) -> syn::Result<Vec<syn::Path>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let paths = Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?;
+    Ok(paths.into_iter().collect())
+}
+
+pub fn parse_orm_args<T: Parse>(attr: &syn::Attribute) -> Vec<T> {
+    let arguments = attr
+        .meta
+        .require_list()
+        .and_then(|list| list.parse_args_with(Punctuated::<T, Token![,]>::parse_terminated))
+        .unwrap_or_else(|err| abort!(err.span(), "{}", err));
+    arguments.into_iter().collect()
+}
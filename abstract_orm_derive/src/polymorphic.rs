@@ -0,0 +1,150 @@
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use proc_macro_error::abort;
+use syn::{Ident, Type};
+use convert_case::{Case, Casing};
+use crate::util;
+use crate::connection::{Backend, ConnKind};
+
+pub struct PolymorphicAST {
+    original_type: Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
+    associations: Vec<(Ident, Vec<Type>)>,
+    field_names: Vec<Ident>,
+    derives: Vec<syn::Path>,
+}
+
+/// Name of `target` stored in the `_type` column, the last segment of its path.
+fn target_ident(target: &Type) -> Ident {
+    if let Type::Path(type_path) = target {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident.clone();
+        }
+    }
+    abort!(target, "expected the path of an entity");
+}
+
+impl PolymorphicAST {
+    pub fn new(original_type: &Ident, vis: &syn::Visibility, generics: &syn::Generics) -> Self {
+        Self {
+            original_type: original_type.clone(),
+            vis: vis.clone(),
+            generics: generics.clone(),
+            associations: Vec::new(),
+            field_names: Vec::new(),
+            derives: util::default_derives(&["Serialize"], &["Clone"]),
+        }
+    }
+
+    /// The enums holding the related entities derive the same as `XLazy`.
+    pub fn set_derives(&mut self, derives: Vec<syn::Path>){
+        self.derives = derives;
+    }
+
+    pub fn push_association(&mut self, name: Ident, targets: Vec<Type>){
+        self.associations.push((name, targets));
+    }
+
+    pub fn push_field(&mut self, field_name: &Ident){
+        self.field_names.push(field_name.clone());
+    }
+
+    /// The `name_type` or `name_id` column of the association `name`.
+    fn column(&self, name: &Ident, format: &str) -> Ident {
+        let column = util::format_ident(format, name);
+        if !self.field_names.contains(&column) {
+            abort!(name.span(), "`{}` is not a field of `{}`", column, self.original_type);
+        }
+        column
+    }
+
+    fn make_enum(&self, enum_ident: &Ident, name: &Ident, targets: &[Type])->TokenStream2 {
+        let Self {original_type, vis, derives, ..} = self;
+        let variants: Vec<Ident> = targets.iter().map(target_ident).collect();
+        let derive_attrs = util::derive_attrs(derives, &[]);
+        let doc = format!("The entity the `{}` association of [`{}`] points at.", name, original_type);
+        quote!{
+            #[doc = #doc]
+            #derive_attrs
+            #vis enum #enum_ident {
+                #(#variants(#targets)),*
+            }
+        }
+    }
+
+    /// `get_name`, the target of the association found with the `find` of
+    /// the type named by `name_type`, `None` for a type not in the list.
+    fn make_getters(&self, enum_ident: &Ident, name: &Ident, targets: &[Type], backend: Backend)->TokenStream2 {
+        let type_column = self.column(name, "{}_type");
+        let id_column = self.column(name, "{}_id");
+        let get_ident = util::format_ident("get_{}", name);
+        let mut getters: Vec<TokenStream2> = Vec::new();
+        for kind in ConnKind::enabled() {
+            let fn_ident = kind.fn_ident(&get_ident);
+            let find_ident = kind.fn_ident(&Ident::new("find", Span::call_site()));
+            let asyncness = kind.asyncness();
+            let conn_type = kind.conn_type(backend);
+            let dot_await = kind.dot_await();
+            let arms: Vec<TokenStream2> = targets.iter().map(|target| {
+                let variant = target_ident(target);
+                let type_name = variant.to_string();
+                quote!{
+                    #type_name => <#target>::#find_ident(self.#id_column, conn)#dot_await
+                        .expect("could not fetch relation")
+                        .map(#enum_ident::#variant),
+                }
+            }).collect();
+            getters.push(quote!{
+                pub #asyncness fn #fn_ident(&self, conn: #conn_type) -> Option<#enum_ident> {
+                    return match self.#type_column.as_str() {
+                        #(#arms)*
+                        _ => None,
+                    };
+                }
+            });
+        }
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        quote!{
+            impl #impl_generics #original_type #ty_generics #where_clause {
+                #(#getters)*
+            }
+        }
+    }
+
+    fn make_polymorphic_impls(&self, name: &Ident, targets: &[Type], table_name: &TokenStream2)->TokenStream2 {
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let type_column = self.column(name, "{}_type");
+        let impls: Vec<TokenStream2> = targets.iter().map(|target| {
+            let type_name = target_ident(target).to_string();
+            quote!{
+                impl #impl_generics ::abstract_orm::Polymorphic<#target> for #original_type #ty_generics #where_clause {
+                    type OfType = ::diesel::dsl::Eq<#table_name::#type_column, &'static str>;
+
+                    fn of_type() -> Self::OfType {
+                        return ::diesel::ExpressionMethods::eq(#table_name::#type_column, #type_name);
+                    }
+                }
+            }
+        }).collect();
+        quote!{ #(#impls)* }
+    }
+
+    /// For every `#[polymorphic(name, types(...))]`, the `Name` enum of
+    /// its targets, the `get_name` getter and the `Polymorphic` impls.
+    pub fn build(&self, table_name: Option<TokenStream2>, backend: Backend)->TokenStream2 {
+        let table_name = match table_name {
+            Some(table_name) => table_name,
+            None => return quote!{},
+        };
+        let mut output: Vec<TokenStream2> = Vec::new();
+        for (name, targets) in &self.associations {
+            let enum_ident = Ident::new(&name.to_string().to_case(Case::UpperCamel), Span::call_site());
+            output.push(self.make_enum(&enum_ident, name, targets));
+            output.push(self.make_getters(&enum_ident, name, targets, backend));
+            output.push(self.make_polymorphic_impls(name, targets, &table_name));
+        }
+        quote!{ #(#output)* }
+    }
+}
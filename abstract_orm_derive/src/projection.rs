@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::abort;
+use syn::Ident;
+use std::collections::BTreeMap;
+use crate::util;
+use crate::connection::Backend;
+
+pub struct ProjectionAST {
+    original_type: Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
+    projections: Vec<(Ident, Vec<Ident>)>,
+    fields: BTreeMap<Ident, syn::Field>,
+    derives: Vec<syn::Path>,
+}
+
+impl ProjectionAST {
+    pub fn new(original_type: &Ident, vis: &syn::Visibility, generics: &syn::Generics) -> Self {
+        Self {
+            original_type: original_type.clone(),
+            vis: vis.clone(),
+            generics: generics.clone(),
+            projections: Vec::new(),
+            fields: BTreeMap::new(),
+            derives: util::default_derives(&["Serialize"], &["Clone", "Debug"]),
+        }
+    }
+
+    pub fn push_projection(&mut self, projection_ident: Ident, field_names: Vec<Ident>){
+        self.projections.push((projection_ident, field_names));
+    }
+
+    pub fn push_field(&mut self, field_name: &Ident, field: &syn::Field){
+        self.fields.insert(field_name.clone(), field.clone());
+    }
+
+    /// `Projection` impl of `projection_type`, selecting its columns next to the key.
+    fn make_projection_impl(&self, projection_type: TokenStream2, table_name: &TokenStream2, backend: Backend)->TokenStream2 {
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let backend_type = backend.backend_type();
+        quote!{
+            impl #impl_generics ::abstract_orm::Projection<#original_type #ty_generics> for #projection_type #where_clause {
+                fn load_keyed(
+                    filter: Option<::abstract_orm::ProjectionFilter<#original_type #ty_generics>>,
+                    key: ::abstract_orm::ProjectionKey<#original_type #ty_generics>,
+                    conn: &mut <#original_type #ty_generics as ::abstract_orm::Entity>::Connection,
+                ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+                    let mut query = ::diesel::QueryDsl::into_boxed::<#backend_type>(::diesel::QueryDsl::select(
+                        #table_name::table,
+                        (key, <Self as ::diesel::SelectableHelper<#backend_type>>::as_select()),
+                    ));
+                    if let Some(filter) = filter {
+                        query = ::diesel::QueryDsl::filter(query, filter);
+                    }
+                    return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+                        .map_err(::abstract_orm::OrmError::from);
+                }
+            }
+        }
+    }
+
+    fn make_projection(&self, projection_ident: &Ident, field_names: &[Ident], table_name: &TokenStream2, backend: Backend)->TokenStream2 {
+        let Self {original_type, vis, fields, derives, ..} = self;
+        let with_serde = util::derives_serde(derives);
+        let mut projection_fields: Vec<syn::Field> = Vec::new();
+        for field_name in field_names {
+            let mut field = match fields.get(field_name) {
+                Some(field) => field.clone(),
+                None => abort!(field_name.span(), "`{}` is not a field of `{}`", field_name, original_type),
+            };
+            field.attrs.retain(|attr| {
+                let path = attr.meta.path();
+                path.is_ident("diesel") || path.is_ident("doc") || path.is_ident("cfg") || (with_serde && path.is_ident("serde"))
+            });
+            projection_fields.push(field);
+        }
+        let derive_attrs = util::derive_attrs(derives, &[]);
+        let projection_impl = self.make_projection_impl(quote!{#projection_ident}, table_name, backend);
+        let doc = format!("Columns {} of [`{}`].", field_names.iter().map(|f| format!("`{}`", f)).collect::<Vec<_>>().join(", "), original_type);
+        quote!{
+            #[doc = #doc]
+            #[derive(::diesel::Queryable, ::diesel::Selectable)]
+            #derive_attrs
+            #[diesel(table_name = #table_name)]
+            #vis struct #projection_ident {
+                #(#projection_fields),*
+            }
+            #projection_impl
+        }
+    }
+
+    /// The declared projections, and the entity as the projection of all
+    /// its columns.
+    pub fn build(&self, table_name: Option<TokenStream2>, backend: Backend)->TokenStream2 {
+        let table_name = match table_name {
+            Some(table_name) => table_name,
+            None => return quote!{},
+        };
+        let original_type = &self.original_type;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let self_impl = self.make_projection_impl(quote!{#original_type #ty_generics}, &table_name, backend);
+        let projections: Vec<TokenStream2> = self.projections.iter()
+            .map(|(projection_ident, field_names)| self.make_projection(projection_ident, field_names, &table_name, backend))
+            .collect();
+        quote!{
+            #self_impl
+            #(#projections)*
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Expansion snapshots of `#[derive(Joinable)]`, stored formatted under
+//! `tests/expand`. Run with `UPDATE_EXPAND=1` to accept a changed expansion.
+
+use proc_macro2::TokenStream as TokenStream2;
+use std::{env, fs, path::PathBuf};
+use crate::expand_joinable;
+
+fn assert_expansion(name: &str, input: TokenStream2) {
+    let ast: syn::DeriveInput = syn::parse2(input).expect("test input is not a struct");
+    let expanded: syn::File = syn::parse2(expand_joinable(ast)).expect("expansion is not valid rust");
+    let formatted = prettyplease::unparse(&expanded);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/expand")
+        .join(format!("{}.expanded.rs", name));
+    if env::var_os("UPDATE_EXPAND").is_some() || !path.exists() {
+        fs::write(&path, formatted).expect("can not write expansion snapshot");
+        return;
+    }
+    let expected = fs::read_to_string(&path).expect("can not read expansion snapshot");
+    assert!(expected == formatted, "expansion of `{}` changed, rerun with UPDATE_EXPAND=1 to accept:\n{}", name, formatted);
+}
+
+#[cfg(all(feature = "serde", not(feature = "async")))]
+mod sync {
+    use super::assert_expansion;
+
+    #[test]
+    fn without_relations() {
+        assert_expansion("without_relations", quote!{
+            #[diesel(table_name = roles)]
+            pub struct Role {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn many_to_one() {
+        assert_expansion("many_to_one", quote!{
+            #[diesel(table_name = posts)]
+            #[diesel(belongs_to(User))]
+            pub struct Post {
+                pub id: i64,
+                #[many_to_one(User)]
+                pub user_id: i64,
+                #[many_to_one(Option<Category>)]
+                pub category_id: Option<i64>,
+                pub title: String,
+            }
+        });
+    }
+
+    #[test]
+    fn one_to_many() {
+        assert_expansion("one_to_many", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post)]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn ordered_one_to_many() {
+        assert_expansion("ordered_one_to_many", quote!{
+            #[diesel(table_name = categories)]
+            #[one_to_many(posts = Post, order_by = created_at desc, drafts = Draft)]
+            pub struct Category {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn scoped_relations() {
+        assert_expansion("scoped_relations", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post, published_posts = Post, where = posts::published.eq(true))]
+            #[many_to_many(admin_roles = Role, where = roles::name.eq("admin"))]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn polymorphic() {
+        assert_expansion("polymorphic", quote!{
+            #[diesel(table_name = comments)]
+            #[polymorphic(commentable, types(Post, Photo))]
+            pub struct Comment {
+                pub id: i64,
+                pub body: String,
+                pub commentable_type: String,
+                pub commentable_id: i64,
+            }
+        });
+    }
+
+    #[test]
+    fn polymorphic_with_lazy_derive() {
+        assert_expansion("polymorphic_with_lazy_derive", quote!{
+            #[diesel(table_name = comments)]
+            #[orm(lazy_derive(Debug))]
+            #[polymorphic(commentable, types(Post, Photo))]
+            pub struct Comment {
+                pub id: i64,
+                pub body: String,
+                pub commentable_type: String,
+                pub commentable_id: i64,
+            }
+        });
+    }
+
+    #[test]
+    fn has_many_through() {
+        assert_expansion("has_many_through", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post)]
+            #[has_many_through(comments_on_my_posts = Comment through posts)]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn many_to_many() {
+        assert_expansion("many_to_many", quote!{
+            #[diesel(table_name = users)]
+            #[many_to_many(roles = Role, tags = Tag by UserTagLink)]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn many_to_many_with_link() {
+        assert_expansion("many_to_many_with_link", quote!{
+            #[diesel(table_name = users)]
+            #[many_to_many(roles = Role by UserRole, with_link)]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+
+    #[test]
+    fn lookups() {
+        assert_expansion("lookups", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post)]
+            pub struct User {
+                pub id: i64,
+                #[orm(unique)]
+                pub email: String,
+                #[orm(index)]
+                pub status: i32,
+                #[orm(unique)]
+                pub nickname: Option<String>,
+            }
+        });
+    }
+
+    #[test]
+    fn join_table() {
+        assert_expansion("join_table", quote!{
+            #[diesel(table_name = user_roles)]
+            #[diesel(primary_key(user_id, role_id))]
+            pub struct UserToRole {
+                pub user_id: i64,
+                pub role_id: i64,
+            }
+        });
+    }
+
+    #[test]
+    fn soft_delete() {
+        assert_expansion("soft_delete", quote!{
+            #[diesel(table_name = posts)]
+            #[orm(soft_delete = deleted_at)]
+            pub struct Post {
+                pub id: i64,
+                #[many_to_one(User)]
+                pub user_id: i64,
+                pub title: String,
+                #[orm(skip_insert)]
+                pub deleted_at: Option<String>,
+            }
+        });
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_expansion("timestamps", quote!{
+            #[diesel(table_name = tags)]
+            #[orm(created_at = inserted_at, updated_at = changed_at)]
+            pub struct Tag {
+                pub id: i64,
+                pub name: String,
+                pub inserted_at: String,
+                pub changed_at: String,
+            }
+        });
+    }
+
+    #[test]
+    fn version() {
+        assert_expansion("version", quote!{
+            #[diesel(table_name = tags)]
+            #[orm(version = lock_version)]
+            pub struct Tag {
+                pub id: i64,
+                pub name: String,
+                pub lock_version: i32,
+            }
+        });
+    }
+
+    #[test]
+    fn hooks() {
+        assert_expansion("hooks", quote!{
+            #[diesel(table_name = user_roles)]
+            #[diesel(primary_key(user_id, role_id))]
+            #[orm(hooks)]
+            pub struct UserToRole {
+                pub user_id: i64,
+                pub role_id: i64,
+            }
+        });
+    }
+
+    #[test]
+    fn validation() {
+        assert_expansion("validation", quote!{
+            #[diesel(table_name = accounts)]
+            #[orm(borrowed_insert)]
+            pub struct Account {
+                pub id: i64,
+                #[validate(email, length(max = 64))]
+                pub email: String,
+                #[orm(default)]
+                #[validate(length(min = 2))]
+                pub name: String,
+                #[validate(range(min = 0, max = 150))]
+                pub age: i32,
+            }
+        });
+    }
+
+    #[test]
+    fn orm_options() {
+        assert_expansion("orm_options", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post)]
+            #[orm(schema = "crate::db::schema", backend = sqlite, borrowed_insert)]
+            #[orm(lazy_derive(Debug, Clone), with_all_derive(Serialize), projection(UserSummary: id, name))]
+            #[serde(rename_all = "camelCase")]
+            pub(crate) struct User {
+                pub id: i64,
+                pub name: String,
+                #[orm(default)]
+                #[serde(default)]
+                pub status: String,
+                #[orm(skip_insert)]
+                pub created_at: String,
+            }
+        });
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "async"))]
+mod with_async {
+    use super::assert_expansion;
+
+    #[test]
+    fn one_to_many_async() {
+        assert_expansion("one_to_many_async", quote!{
+            #[diesel(table_name = users)]
+            #[one_to_many(posts = Post)]
+            pub struct User {
+                pub id: i64,
+                pub name: String,
+            }
+        });
+    }
+}
@@ -0,0 +1,169 @@
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use syn::{Type, Ident, Path, GenericArgument, PathArguments};
+use convert_case::{Case, Casing};
+
+pub fn format_ident(format:&str, ident:&Ident) -> Ident {
+    let format_s = format.to_string();
+    Ident::new(&format_s.replace("{}", &ident.to_string()), Span::call_site())
+}
+#[allow(dead_code)]
+pub fn format_ident_snake(format:&str, ident:&Ident) -> Ident {
+    let format_s = format.to_string();
+    Ident::new(&format_s.replace("{}", &ident.to_string().to_case(Case::Snake)), Span::call_site())
+}
+
+/// Marker type behind the `X::FIELD` relation constant, `UserPostsRelation`
+/// for `posts` of `User`.
+pub fn relation_marker_ident(original_type: &Ident, field_name: &Ident) -> Ident {
+    let field_camel = field_name.to_string().to_case(Case::UpperCamel);
+    Ident::new(&format!("{}{}Relation", original_type, field_camel), Span::call_site())
+}
+
+pub fn relation_const_ident(field_name: &Ident) -> Ident {
+    Ident::new(&field_name.to_string().to_case(Case::UpperSnake), Span::call_site())
+}
+
+fn path_is_option(path: &Path) -> bool {
+    path.leading_colon.is_none()
+        && path.segments.len() == 1
+        && path.segments.iter().next().unwrap().ident == "Option"
+}
+
+pub fn type_is_option(type_name: &Type) -> bool {
+    if let Type::Path(typepath) = type_name {
+        return typepath.qself.is_none() && path_is_option(&typepath.path);
+    }
+    false
+}
+
+pub fn type_is_i64(type_name: &Type) -> bool {
+    matches!(type_name, Type::Path(typepath) if typepath.qself.is_none() && typepath.path.is_ident("i64"))
+}
+
+pub fn type_contains(type_name: &Type, pattern: &str) -> bool {
+    let string = format!{"{}", quote!{ #type_name }};
+
+    string.contains(pattern)
+}
+
+pub fn extract_type_from_option(ty: &Type) -> Type {
+    match ty {
+        Type::Path(typepath) if typepath.qself.is_none() && path_is_option(&typepath.path) => {
+            // Get the first segment of the path (there is only one, in fact: "Option"):
+            let type_params = typepath.path.segments.first().unwrap().arguments.clone();
+            // It should have only on angle-bracketed param ("<String>"):
+            match type_params {
+                PathArguments::AngleBracketed(params) => {
+                    let generic_arg = params.args.first().unwrap();
+                    match generic_arg {
+                        GenericArgument::Type(ty) => ty.clone(),
+                        _ => panic!("TODO: error handling"),
+                    }
+                },
+                _ => panic!("TODO: error handling"),
+            }
+        }
+        _ => ty.clone(),
+    }
+}
+
+/// Generics of the generated `NewX`, with the `'a` lifetime of its borrowed
+/// fields added in front when `borrowed` is set.
+pub fn insert_generics(generics: &syn::Generics, borrowed: bool) -> syn::Generics {
+    let mut insert_generics = generics.clone();
+    let has_lifetime = generics.lifetimes().any(|lifetime| lifetime.lifetime.ident == "a");
+    if borrowed && !has_lifetime {
+        insert_generics.params.insert(0, syn::parse_quote!{'a});
+    }
+    insert_generics
+}
+
+/// Borrowed form of an owned field type for the `'a` insertable,
+/// `String` becomes `&'a str` and `Vec<u8>` becomes `&'a [u8]`.
+pub fn borrowed_type(ty: &Type) -> Type {
+    if type_is_option(ty) {
+        let inner = borrowed_type(&extract_type_from_option(ty));
+        return syn::parse_quote!{Option<#inner>};
+    }
+    let type_str = format!{"{}", quote!{ #ty }}.replace(' ', "");
+    match type_str.as_str() {
+        "String" => syn::parse_quote!{&'a str},
+        "Vec<u8>" => syn::parse_quote!{&'a [u8]},
+        _ => ty.clone(),
+    }
+}
+
+/// Argument type of a `find_by_x` lookup on a field of type `ty`, `String`
+/// is taken as `&str` and `Vec<u8>` as `&[u8]`.
+pub fn lookup_type(ty: &Type) -> Type {
+    if type_is_option(ty) {
+        let inner = lookup_type(&extract_type_from_option(ty));
+        return syn::parse_quote!{Option<#inner>};
+    }
+    let type_str = format!{"{}", quote!{ #ty }}.replace(' ', "");
+    match type_str.as_str() {
+        "String" => syn::parse_quote!{&str},
+        "Vec<u8>" => syn::parse_quote!{&[u8]},
+        _ => ty.clone(),
+    }
+}
+
+pub fn make_type_option(ty: &Type) -> Type {
+    syn::parse_quote!{Option::<#ty>}
+}
+
+/// Brings the diesel query traits into scope for generated code without
+/// relying on the caller's imports or binding any names.
+pub fn diesel_imports() -> TokenStream2 {
+    quote!{
+        #[allow(unused_imports)]
+        use ::diesel::{
+            BelongingToDsl as _,
+            BoolExpressionMethods as _,
+            ExpressionMethods as _,
+            JoinOnDsl as _,
+            NullableExpressionMethods as _,
+            OptionalExtension as _,
+            QueryDsl as _,
+            SelectableHelper as _,
+        };
+    }
+}
+
+/// Derives a generated type gets when no `#[orm(..._derive(...))]` is given,
+/// the serde ones only with the `serde` feature.
+pub fn default_derives(serde_derives: &[&str], other_derives: &[&str]) -> Vec<Path> {
+    let mut names: Vec<&str> = Vec::new();
+    if cfg!(feature = "serde") {
+        names.extend(serde_derives);
+    }
+    names.extend(other_derives);
+    names.into_iter().map(|name| Ident::new(name, Span::call_site()).into()).collect()
+}
+
+pub fn derives_serde(derives: &[Path]) -> bool {
+    derives.iter().any(|path| {
+        path.segments.last().is_some_and(|last| last.ident == "Serialize" || last.ident == "Deserialize")
+    })
+}
+
+/// `#[derive(...)]` for a generated type, bare `Serialize`/`Deserialize` resolve
+/// to the serde crate. The `#[serde(...)]` attributes of the source struct are
+/// only passed on when serde is derived.
+pub fn derive_attrs(derives: &[Path], serde_attrs: &[syn::Attribute]) -> TokenStream2 {
+    let serde_attrs = if derives_serde(derives) { serde_attrs } else { &[] };
+    let derives: Vec<TokenStream2> = derives.iter().map(|path| {
+        if path.is_ident("Serialize") || path.is_ident("Deserialize") {
+            quote!{::serde::#path}
+        } else {
+            quote!{#path}
+        }
+    }).collect();
+    if derives.is_empty() {
+        return quote!{};
+    }
+    quote!{
+        #[derive(#(#derives),*)]
+        #(#serde_attrs)*
+    }
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Ident, Type};
+use crate::util;
+use crate::orm_attr::ValidateRule;
+
+/// A field with `#[validate(...)]` rules, with its type in `NewX` when
+/// the field is inserted.
+struct ValidatedField {
+    field_name: Ident,
+    field_type: Type,
+    new_type: Option<Type>,
+    rules: Vec<ValidateRule>,
+}
+
+pub struct ValidationAST {
+    original_type: Ident,
+    generics: syn::Generics,
+    fields: Vec<ValidatedField>,
+}
+
+impl ValidationAST {
+    pub fn new(original_type: &Ident, generics: &syn::Generics) -> Self {
+        Self {
+            original_type: original_type.clone(),
+            generics: generics.clone(),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn push_field(&mut self, field_name: &Ident, field_type: &Type, rules: Vec<ValidateRule>){
+        self.fields.push(ValidatedField {
+            field_name: field_name.clone(),
+            field_type: field_type.clone(),
+            new_type: None,
+            rules,
+        });
+    }
+
+    /// Records the type `field_name` has in `NewX`.
+    pub fn set_new_type(&mut self, field_name: &Ident, new_type: &Type){
+        if let Some(field) = self.fields.iter_mut().find(|field| &field.field_name == field_name) {
+            field.new_type = Some(new_type.clone());
+        }
+    }
+
+    pub fn has_rules(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    /// The check of one rule on `value`, a reference to the field value.
+    fn make_rule_check(rule: &ValidateRule) -> TokenStream2 {
+        let bound = |bound: &Option<syn::Expr>| match bound {
+            Some(bound) => quote!{Some(#bound)},
+            None => quote!{None},
+        };
+        match rule {
+            ValidateRule::Length { min, max } => {
+                let (min, max) = (bound(min), bound(max));
+                quote!{ ::abstract_orm::check_length(::std::convert::AsRef::<str>::as_ref(value), #min, #max) }
+            },
+            ValidateRule::Email => {
+                quote!{ ::abstract_orm::check_email(::std::convert::AsRef::<str>::as_ref(value)) }
+            },
+            ValidateRule::Range { min, max } => {
+                let (min, max) = (bound(min), bound(max));
+                quote!{ ::abstract_orm::check_range(value, #min, #max) }
+            },
+        }
+    }
+
+    /// `validate`, checking the rules of the given fields with their type.
+    fn make_validate_fn(fields: Vec<(&ValidatedField, &Type)>) -> TokenStream2 {
+        let mut checks: Vec<TokenStream2> = Vec::new();
+        for (field, field_type) in fields {
+            let field_name = &field.field_name;
+            let field_label = field_name.to_string();
+            let rule_checks: Vec<TokenStream2> = field.rules.iter().map(Self::make_rule_check).collect();
+            let checks_on_value = quote!{
+                #(
+                    if let Some(message) = #rule_checks {
+                        errors.push(::abstract_orm::FieldError { field: #field_label, message });
+                    }
+                )*
+            };
+            if util::type_is_option(field_type) {
+                checks.push(quote!{
+                    if let Some(value) = &self.#field_name {
+                        #checks_on_value
+                    }
+                });
+            } else {
+                checks.push(quote!{
+                    {
+                        let value = &self.#field_name;
+                        #checks_on_value
+                    }
+                });
+            }
+        }
+        quote!{
+            /// Checks the `#[validate(...)]` rules, returning every field that fails one.
+            pub fn validate(&self) -> Result<(), Vec<::abstract_orm::FieldError>> {
+                let mut errors: Vec<::abstract_orm::FieldError> = Vec::new();
+                #(#checks)*
+                if errors.len() > 0 {
+                    return Err(errors);
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    /// `validate` on the entity, checked by `update`, and on `NewX` for the
+    /// inserted fields, checked by `insert`.
+    pub fn build(&self, borrowed_insert: bool)->TokenStream2 {
+        if !self.has_rules() {
+            return quote!{};
+        }
+        let original_type = &self.original_type;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let ident_save = util::format_ident("New{}", original_type);
+        let new_generics = util::insert_generics(&self.generics, borrowed_insert);
+        let (new_impl_generics, new_ty_generics, new_where_clause) = new_generics.split_for_impl();
+        let entity_validate = Self::make_validate_fn(self.fields.iter()
+            .map(|field| (field, &field.field_type))
+            .collect());
+        let new_validate = Self::make_validate_fn(self.fields.iter()
+            .filter_map(|field| field.new_type.as_ref().map(|new_type| (field, new_type)))
+            .collect());
+        quote!{
+            impl #impl_generics #original_type #ty_generics #where_clause {
+                #entity_validate
+            }
+            impl #new_impl_generics #ident_save #new_ty_generics #new_where_clause {
+                #new_validate
+            }
+        }
+    }
+}
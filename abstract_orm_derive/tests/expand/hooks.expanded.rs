@@ -0,0 +1,188 @@
+#[derive(::diesel::Insertable)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[diesel(table_name = user_roles)]
+pub struct NewUserToRole {
+    pub user_id: i64,
+    pub role_id: i64,
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::diesel::{
+        BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _,
+        JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+        QueryDsl as _, SelectableHelper as _,
+    };
+    impl UserToRole {
+        pub fn get_table_ref() -> user_roles::table {
+            return user_roles::table;
+        }
+        pub fn find_all(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Self> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn).unwrap();
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    user_roles::table
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select()),
+                    conn,
+                )
+                .unwrap();
+        }
+        pub fn insert(
+            mut data: NewUserToRole,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<NewUserToRole, ::abstract_orm::OrmError> {
+            <Self as ::abstract_orm::Hooks>::before_insert(&mut data)?;
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::insert_into(user_roles::table).values(&data),
+                conn,
+            )?;
+            if let Some(inserted) = Self::find(
+                ::std::clone::Clone::clone(&data.role_id),
+                ::std::clone::Clone::clone(&data.user_id),
+                conn,
+            )? {
+                <Self as ::abstract_orm::Hooks>::after_insert(&inserted)?;
+            }
+            Ok(data)
+        }
+        pub fn delete(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            <Self as ::abstract_orm::Hooks>::before_delete(self)?;
+            let deleted_rows = ::diesel::RunQueryDsl::execute(
+                ::diesel::delete(
+                    user_roles::table
+                        .filter(user_roles::user_id.eq(&self.user_id))
+                        .filter(user_roles::role_id.eq(&self.role_id)),
+                ),
+                conn,
+            )?;
+            if deleted_rows > 0 {
+                <Self as ::abstract_orm::Hooks>::after_delete(self)?;
+            }
+            Ok(())
+        }
+        pub fn find(
+            role_id: i64,
+            user_id: i64,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Option<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let result = ::diesel::RunQueryDsl::first::<
+                Self,
+            >(
+                    user_roles::table
+                        .filter(user_roles::user_id.eq(user_id))
+                        .filter(user_roles::role_id.eq(role_id)),
+                    conn,
+                )
+                .optional()?;
+            Ok(result)
+        }
+        pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(
+                None,
+                Box::new(user_roles::user_id.nullable()),
+                conn,
+            )?;
+            return Ok(rows.into_iter().map(|(_, data)| data).collect());
+        }
+    }
+    impl ::abstract_orm::Entity for UserToRole {
+        type Table = user_roles::table;
+        type Key = (i64, i64);
+        type New<'a> = NewUserToRole where Self: 'a;
+        type Lazy = Self;
+        type WithAll = Self;
+        type Connection = ::diesel::mysql::MysqlConnection;
+        fn get_table_ref() -> Self::Table {
+            return user_roles::table;
+        }
+        fn find(
+            key: Self::Key,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+            let (role_id, user_id) = key;
+            return UserToRole::find(role_id, user_id, conn)
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn find_all(
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    user_roles::table.select(Self::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn insert<'a>(
+            data: Self::New<'a>,
+            conn: &mut Self::Connection,
+        ) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+            return UserToRole::insert(data, conn);
+        }
+    }
+    impl ::abstract_orm::SoftDelete for UserToRole {
+        type NotDeleted = ::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>;
+        fn not_deleted() -> Self::NotDeleted {
+            return ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true);
+        }
+    }
+};
+impl ::abstract_orm::Projection<UserToRole> for UserToRole {
+    fn load_keyed(
+        filter: Option<::abstract_orm::ProjectionFilter<UserToRole>>,
+        key: ::abstract_orm::ProjectionKey<UserToRole>,
+        conn: &mut <UserToRole as ::abstract_orm::Entity>::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+        let mut query = ::diesel::QueryDsl::into_boxed::<
+            ::diesel::mysql::Mysql,
+        >(
+            ::diesel::QueryDsl::select(
+                user_roles::table,
+                (
+                    key,
+                    <Self as ::diesel::SelectableHelper<
+                        ::diesel::mysql::Mysql,
+                    >>::as_select(),
+                ),
+            ),
+        );
+        if let Some(filter) = filter {
+            query = ::diesel::QueryDsl::filter(query, filter);
+        }
+        return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+            .map_err(::abstract_orm::OrmError::from);
+    }
+}
+impl UserToRole {
+    pub fn get_for_user_id(&self) -> i64 {
+        self.role_id
+    }
+    pub fn get_for_role_id(&self) -> i64 {
+        self.user_id
+    }
+}
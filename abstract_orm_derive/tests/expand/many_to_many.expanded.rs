@@ -0,0 +1,1315 @@
+#[derive(::diesel::Insertable)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[diesel(table_name = users)]
+pub struct NewUser {
+    pub name: String,
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct UserWithAll {
+    #[serde(flatten)]
+    pub self_data: User,
+    pub roles: Vec<Role>,
+    pub tags: Vec<Tag>,
+}
+impl UserWithAll {
+    pub fn new_builder(
+        (self_data, roles, tags): (User, Option<Role>, Option<Tag>),
+    ) -> Self {
+        Self {
+            self_data,
+            roles: if let Some(data) = roles { vec![data] } else { Vec::new() },
+            tags: if let Some(data) = tags { vec![data] } else { Vec::new() },
+        }
+    }
+    pub fn insert_data(
+        &mut self,
+        (self_data, roles, tags): (User, Option<Role>, Option<Tag>),
+    ) {
+        if let Some(data) = roles {
+            self.roles.push(data);
+        }
+        if let Some(data) = tags {
+            self.tags.push(data);
+        }
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::diesel::{
+        BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _,
+        JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+        QueryDsl as _, SelectableHelper as _,
+    };
+    impl User {
+        pub fn get_table_ref() -> users::table {
+            return users::table;
+        }
+        pub fn find_all(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Self> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn).unwrap();
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    users::table
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select()),
+                    conn,
+                )
+                .unwrap();
+        }
+        pub fn insert(
+            data: NewUser,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<NewUser, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::insert_into(users::table).values(&data),
+                conn,
+            )?;
+            Ok(data)
+        }
+        pub fn update(
+            &mut self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::update(users::table.filter(users::id.eq(&self.id)))
+                    .set((users::name.eq(&self.name),)),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn delete(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::delete(users::table.filter(users::id.eq(&self.id))),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn find(
+            uid: i64,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Option<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let result = ::diesel::RunQueryDsl::first::<
+                Self,
+            >(users::table.filter(users::id.eq(uid)), conn)
+                .optional()?;
+            Ok(result)
+        }
+        pub fn find_all_eager(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserWithAll>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<UserWithAll> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Role>, Option<Tag>),
+            >(
+                users::table
+                    .left_join(
+                        UserToRole::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserToRole::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Role::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserToRole::get_table_ref(),
+                                        Role::get_table_ref(),
+                                    )
+                                    .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .left_join(
+                        UserTagLink::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserTagLink::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Tag::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserTagLink::get_table_ref(),
+                                        Tag::get_table_ref(),
+                                    )
+                                    .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((
+                        Self::as_select(),
+                        Option::<Role>::as_select(),
+                        Option::<Tag>::as_select(),
+                    )),
+                conn,
+            )?;
+            for query_row in all_rows {
+                let current_id = query_row.0.id;
+                if last_id == current_id {
+                    ret_data.last_mut().unwrap().insert_data(query_row);
+                } else {
+                    let data = UserWithAll::new_builder(query_row);
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_roles(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<UserLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Role>),
+            >(
+                users::table
+                    .left_join(
+                        UserToRole::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserToRole::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Role::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserToRole::get_table_ref(),
+                                        Role::get_table_ref(),
+                                    )
+                                    .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), Option::<Role>::as_select())),
+                conn,
+            )?;
+            for (self_data, roles) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    if let Some(val) = roles {
+                        data.push_or_set_roles(val);
+                    }
+                } else {
+                    let mut data = UserLazy::init(self_data);
+                    if let Some(val) = roles {
+                        data.push_or_set_roles(val);
+                    }
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_tags(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<UserLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Tag>),
+            >(
+                users::table
+                    .left_join(
+                        UserTagLink::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserTagLink::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Tag::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserTagLink::get_table_ref(),
+                                        Tag::get_table_ref(),
+                                    )
+                                    .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), Option::<Tag>::as_select())),
+                conn,
+            )?;
+            for (self_data, tags) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    if let Some(val) = tags {
+                        data.push_or_set_tags(val);
+                    }
+                } else {
+                    let mut data = UserLazy::init(self_data);
+                    if let Some(val) = tags {
+                        data.push_or_set_tags(val);
+                    }
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_many(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<Self> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                ret_data
+                    .extend(
+                        ::diesel::RunQueryDsl::load(
+                            users::table
+                                .filter(users::id.eq_any(chunk))
+                                .order_by(
+                                    ::abstract_orm::Order::with_primary_key(
+                                        None::<::abstract_orm::Order<Self>>,
+                                    ),
+                                )
+                                .select(Self::as_select()),
+                            conn,
+                        )?,
+                    );
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_map(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<::std::collections::HashMap<i64, Self>, ::diesel::result::Error> {
+            let found = Self::find_many(keys, conn)?;
+            return Ok(found.into_iter().map(|data| (data.id.clone(), data)).collect());
+        }
+        pub fn find_many_with_roles(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<UserLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<UserLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, Option<Role>),
+                >(
+                    users::table
+                        .left_join(
+                            UserToRole::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            User::get_table_ref(),
+                                            UserToRole::get_table_ref(),
+                                        )
+                                        .and(
+                                            <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                        ),
+                                ),
+                        )
+                        .left_join(
+                            Role::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            UserToRole::get_table_ref(),
+                                            Role::get_table_ref(),
+                                        )
+                                        .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                                ),
+                        )
+                        .filter(users::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), Option::<Role>::as_select())),
+                    conn,
+                )?;
+                for (self_data, roles) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        if let Some(val) = roles {
+                            data.push_or_set_roles(val);
+                        }
+                    } else {
+                        let mut data = UserLazy::init(self_data);
+                        if let Some(val) = roles {
+                            data.push_or_set_roles(val);
+                        }
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn find_many_with_tags(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<UserLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<UserLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, Option<Tag>),
+                >(
+                    users::table
+                        .left_join(
+                            UserTagLink::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            User::get_table_ref(),
+                                            UserTagLink::get_table_ref(),
+                                        )
+                                        .and(
+                                            <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                        ),
+                                ),
+                        )
+                        .left_join(
+                            Tag::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            UserTagLink::get_table_ref(),
+                                            Tag::get_table_ref(),
+                                        )
+                                        .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                                ),
+                        )
+                        .filter(users::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), Option::<Tag>::as_select())),
+                    conn,
+                )?;
+                for (self_data, tags) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        if let Some(val) = tags {
+                            data.push_or_set_tags(val);
+                        }
+                    } else {
+                        let mut data = UserLazy::init(self_data);
+                        if let Some(val) = tags {
+                            data.push_or_set_tags(val);
+                        }
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn iter_all(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<Self, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let data = ::diesel::RunQueryDsl::load(
+                        users::table
+                            .filter(users::id.gt(after))
+                            .order_by(users::id)
+                            .limit(batch_size as i64)
+                            .select(Self::as_select()),
+                        conn,
+                    )?;
+                    let last_key = data.last().map(|data| data.id);
+                    return Ok(::abstract_orm::Page {
+                        keys: data.len(),
+                        last_key,
+                        data,
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_roles(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<UserLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        users::table
+                            .filter(users::id.gt(after))
+                            .order_by(users::id)
+                            .limit(batch_size as i64)
+                            .select(users::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<UserLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Role>),
+                    >(
+                        users::table
+                            .left_join(
+                                UserToRole::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                User::get_table_ref(),
+                                                UserToRole::get_table_ref(),
+                                            )
+                                            .and(
+                                                <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .left_join(
+                                Role::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                UserToRole::get_table_ref(),
+                                                Role::get_table_ref(),
+                                            )
+                                            .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(users::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((Self::as_select(), Option::<Role>::as_select())),
+                        conn,
+                    )?;
+                    for (self_data, roles) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            if let Some(val) = roles {
+                                data.push_or_set_roles(val);
+                            }
+                        } else {
+                            let mut data = UserLazy::init(self_data);
+                            if let Some(val) = roles {
+                                data.push_or_set_roles(val);
+                            }
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_tags(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<UserLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        users::table
+                            .filter(users::id.gt(after))
+                            .order_by(users::id)
+                            .limit(batch_size as i64)
+                            .select(users::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<UserLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Tag>),
+                    >(
+                        users::table
+                            .left_join(
+                                UserTagLink::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                User::get_table_ref(),
+                                                UserTagLink::get_table_ref(),
+                                            )
+                                            .and(
+                                                <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .left_join(
+                                Tag::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                UserTagLink::get_table_ref(),
+                                                Tag::get_table_ref(),
+                                            )
+                                            .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(users::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((Self::as_select(), Option::<Tag>::as_select())),
+                        conn,
+                    )?;
+                    for (self_data, tags) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            if let Some(val) = tags {
+                                data.push_or_set_tags(val);
+                            }
+                        } else {
+                            let mut data = UserLazy::init(self_data);
+                            if let Some(val) = tags {
+                                data.push_or_set_tags(val);
+                            }
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_eager(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<UserWithAll, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        users::table
+                            .filter(users::id.gt(after))
+                            .order_by(users::id)
+                            .limit(batch_size as i64)
+                            .select(users::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<UserWithAll> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Role>, Option<Tag>),
+                    >(
+                        users::table
+                            .left_join(
+                                UserToRole::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                User::get_table_ref(),
+                                                UserToRole::get_table_ref(),
+                                            )
+                                            .and(
+                                                <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .left_join(
+                                Role::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                UserToRole::get_table_ref(),
+                                                Role::get_table_ref(),
+                                            )
+                                            .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .left_join(
+                                UserTagLink::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                User::get_table_ref(),
+                                                UserTagLink::get_table_ref(),
+                                            )
+                                            .and(
+                                                <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .left_join(
+                                Tag::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                UserTagLink::get_table_ref(),
+                                                Tag::get_table_ref(),
+                                            )
+                                            .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(users::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((
+                                Self::as_select(),
+                                Option::<Role>::as_select(),
+                                Option::<Tag>::as_select(),
+                            )),
+                        conn,
+                    )?;
+                    for query_row in all_rows {
+                        let current_id = query_row.0.id;
+                        if last_id == current_id {
+                            ret_data.last_mut().unwrap().insert_data(query_row);
+                        } else {
+                            let data = UserWithAll::new_builder(query_row);
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(users::id.nullable()), conn)?;
+            return Ok(rows.into_iter().map(|(_, data)| data).collect());
+        }
+        pub fn find_all_with_roles_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, Vec<R>)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<Role>,
+            R: Clone,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(users::id.nullable()), conn)?;
+            let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+            let foreign_key = <UserToRole as ::diesel::associations::BelongsTo<
+                Self,
+            >>::foreign_key_column();
+            let links = ::diesel::RunQueryDsl::load::<
+                (i64, i64),
+            >(
+                UserToRole::get_table_ref()
+                    .inner_join(Role::get_table_ref())
+                    .filter(foreign_key.eq_any(ids))
+                    .filter(<UserToRole as ::abstract_orm::SoftDelete>::not_deleted())
+                    .filter(<Role as ::abstract_orm::SoftDelete>::not_deleted())
+                    .select((
+                        foreign_key,
+                        ::diesel::Table::primary_key(&Role::get_table_ref()),
+                    )),
+                conn,
+            )?;
+            let related_ids: Vec<i64> = links.iter().map(|link| link.1).collect();
+            let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                    Some(
+                        Box::new(
+                            ::diesel::Table::primary_key(&Role::get_table_ref())
+                                .eq_any(related_ids)
+                                .nullable(),
+                        ),
+                    ),
+                    Box::new(
+                        ::diesel::Table::primary_key(&Role::get_table_ref()).nullable(),
+                    ),
+                    conn,
+                )?
+                .into_iter()
+                .collect();
+            let mut positions: ::std::collections::HashMap<i64, usize> = ::std::collections::HashMap::new();
+            let mut ret_data: Vec<(P, Vec<R>)> = Vec::new();
+            for (id, data) in rows {
+                if let Some(id) = id {
+                    positions.insert(id, ret_data.len());
+                }
+                ret_data.push((data, Vec::new()));
+            }
+            for (id, related_id) in links {
+                if let (Some(position), Some(related_data)) = (
+                    positions.get(&id),
+                    related.get(&Some(related_id)),
+                ) {
+                    ret_data[*position].1.push(related_data.clone());
+                }
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_all_with_tags_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, Vec<R>)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<Tag>,
+            R: Clone,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(users::id.nullable()), conn)?;
+            let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+            let foreign_key = <UserTagLink as ::diesel::associations::BelongsTo<
+                Self,
+            >>::foreign_key_column();
+            let links = ::diesel::RunQueryDsl::load::<
+                (i64, i64),
+            >(
+                UserTagLink::get_table_ref()
+                    .inner_join(Tag::get_table_ref())
+                    .filter(foreign_key.eq_any(ids))
+                    .filter(<UserTagLink as ::abstract_orm::SoftDelete>::not_deleted())
+                    .filter(<Tag as ::abstract_orm::SoftDelete>::not_deleted())
+                    .select((
+                        foreign_key,
+                        ::diesel::Table::primary_key(&Tag::get_table_ref()),
+                    )),
+                conn,
+            )?;
+            let related_ids: Vec<i64> = links.iter().map(|link| link.1).collect();
+            let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                    Some(
+                        Box::new(
+                            ::diesel::Table::primary_key(&Tag::get_table_ref())
+                                .eq_any(related_ids)
+                                .nullable(),
+                        ),
+                    ),
+                    Box::new(
+                        ::diesel::Table::primary_key(&Tag::get_table_ref()).nullable(),
+                    ),
+                    conn,
+                )?
+                .into_iter()
+                .collect();
+            let mut positions: ::std::collections::HashMap<i64, usize> = ::std::collections::HashMap::new();
+            let mut ret_data: Vec<(P, Vec<R>)> = Vec::new();
+            for (id, data) in rows {
+                if let Some(id) = id {
+                    positions.insert(id, ret_data.len());
+                }
+                ret_data.push((data, Vec::new()));
+            }
+            for (id, related_id) in links {
+                if let (Some(position), Some(related_data)) = (
+                    positions.get(&id),
+                    related.get(&Some(related_id)),
+                ) {
+                    ret_data[*position].1.push(related_data.clone());
+                }
+            }
+            return Ok(ret_data);
+        }
+        pub fn get_roles(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Role> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)
+                .expect("could not get a connection");
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    UserToRole::belonging_to(&self)
+                        .inner_join(Role::get_table_ref())
+                        .filter(
+                            <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                        )
+                        .filter(<Role as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Role::as_select()),
+                    conn,
+                )
+                .expect("error running query to fetch many to many relationship");
+        }
+        pub fn get_tags(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Tag> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)
+                .expect("could not get a connection");
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    UserTagLink::belonging_to(&self)
+                        .inner_join(Tag::get_table_ref())
+                        .filter(
+                            <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                        )
+                        .filter(<Tag as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Tag::as_select()),
+                    conn,
+                )
+                .expect("error running query to fetch many to many relationship");
+        }
+        pub fn load_with<R: ::abstract_orm::Relations<Self>>(
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<UserLazy>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy: Vec<UserLazy> = <Self as ::abstract_orm::Entity>::find_all(
+                    conn,
+                )?
+                .into_iter()
+                .map(UserLazy::init)
+                .collect();
+            relations.load_into(&mut lazy, conn)?;
+            return Ok(lazy);
+        }
+        pub fn with_relations<R: ::abstract_orm::Relations<Self>>(
+            self,
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<UserLazy, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy = [UserLazy::init(self)];
+            relations.load_into(&mut lazy, conn)?;
+            let [lazy] = lazy;
+            return Ok(lazy);
+        }
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        fn __assert_relations() {
+            fn relation_needs_joinable<Left, Right>(_: Left, _: Right)
+            where
+                Left: ::diesel::JoinTo<Right>,
+            {}
+            fn relation_needs_allow_tables_to_appear_in_same_query<Left, Right>(
+                _: Left,
+                _: Right,
+            )
+            where
+                Left: ::diesel::query_source::TableNotEqual<Right>,
+                Right: ::diesel::Table,
+            {}
+            fn relation_needs_belongs_to<Child, Parent>()
+            where
+                Child: ::diesel::associations::BelongsTo<Parent>,
+            {}
+            relation_needs_joinable(Self::get_table_ref(), UserToRole::get_table_ref());
+            relation_needs_joinable(UserToRole::get_table_ref(), Role::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                UserToRole::get_table_ref(),
+            );
+            relation_needs_allow_tables_to_appear_in_same_query(
+                UserToRole::get_table_ref(),
+                Role::get_table_ref(),
+            );
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                Role::get_table_ref(),
+            );
+            relation_needs_belongs_to::<UserToRole, Self>();
+            relation_needs_joinable(Self::get_table_ref(), UserTagLink::get_table_ref());
+            relation_needs_joinable(UserTagLink::get_table_ref(), Tag::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                UserTagLink::get_table_ref(),
+            );
+            relation_needs_allow_tables_to_appear_in_same_query(
+                UserTagLink::get_table_ref(),
+                Tag::get_table_ref(),
+            );
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                Tag::get_table_ref(),
+            );
+            relation_needs_belongs_to::<UserTagLink, Self>();
+        }
+    }
+    impl ::abstract_orm::Entity for User {
+        type Table = users::table;
+        type Key = i64;
+        type New<'a> = NewUser where Self: 'a;
+        type Lazy = UserLazy;
+        type WithAll = UserWithAll;
+        type Connection = ::diesel::mysql::MysqlConnection;
+        fn get_table_ref() -> Self::Table {
+            return users::table;
+        }
+        fn find(
+            key: Self::Key,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+            let uid = key;
+            return User::find(uid, conn).map_err(::abstract_orm::OrmError::from);
+        }
+        fn find_all(
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    users::table.select(Self::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn insert<'a>(
+            data: Self::New<'a>,
+            conn: &mut Self::Connection,
+        ) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+            return User::insert(data, conn).map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::SoftDelete for User {
+        type NotDeleted = ::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>;
+        fn not_deleted() -> Self::NotDeleted {
+            return ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true);
+        }
+    }
+    impl ::abstract_orm::HasMany<Role> for User {
+        fn load_children(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Role>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    UserToRole::belonging_to(&self)
+                        .inner_join(Role::get_table_ref())
+                        .filter(
+                            <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                        )
+                        .filter(<Role as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Role::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::HasMany<Tag> for User {
+        fn load_children(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Tag>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    UserTagLink::belonging_to(&self)
+                        .inner_join(Tag::get_table_ref())
+                        .filter(
+                            <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                        )
+                        .filter(<Tag as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Tag::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::Relation<User> for UserRolesRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [UserLazy],
+            conn: &mut <User as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, roles) in ::diesel::RunQueryDsl::load::<
+                (i64, Option<Role>),
+            >(
+                users::table
+                    .left_join(
+                        UserToRole::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserToRole::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserToRole as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Role::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserToRole::get_table_ref(),
+                                        Role::get_table_ref(),
+                                    )
+                                    .and(<Role as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .filter(users::id.eq_any(ids))
+                    .select((users::id, Option::<Role>::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                if let Some(val) = roles {
+                    data.push_or_set_roles(val);
+                }
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<User> for UserRolesRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [UserLazy],
+            conn: &mut <User as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+    impl ::abstract_orm::Relation<User> for UserTagsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [UserLazy],
+            conn: &mut <User as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, tags) in ::diesel::RunQueryDsl::load::<
+                (i64, Option<Tag>),
+            >(
+                users::table
+                    .left_join(
+                        UserTagLink::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        User::get_table_ref(),
+                                        UserTagLink::get_table_ref(),
+                                    )
+                                    .and(
+                                        <UserTagLink as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .left_join(
+                        Tag::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        UserTagLink::get_table_ref(),
+                                        Tag::get_table_ref(),
+                                    )
+                                    .and(<Tag as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .filter(users::id.eq_any(ids))
+                    .select((users::id, Option::<Tag>::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                if let Some(val) = tags {
+                    data.push_or_set_tags(val);
+                }
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<User> for UserTagsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [UserLazy],
+            conn: &mut <User as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+};
+impl ::abstract_orm::Projection<User> for User {
+    fn load_keyed(
+        filter: Option<::abstract_orm::ProjectionFilter<User>>,
+        key: ::abstract_orm::ProjectionKey<User>,
+        conn: &mut <User as ::abstract_orm::Entity>::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+        let mut query = ::diesel::QueryDsl::into_boxed::<
+            ::diesel::mysql::Mysql,
+        >(
+            ::diesel::QueryDsl::select(
+                users::table,
+                (
+                    key,
+                    <Self as ::diesel::SelectableHelper<
+                        ::diesel::mysql::Mysql,
+                    >>::as_select(),
+                ),
+            ),
+        );
+        if let Some(filter) = filter {
+            query = ::diesel::QueryDsl::filter(query, filter);
+        }
+        return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+            .map_err(::abstract_orm::OrmError::from);
+    }
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct UserLazy {
+    #[serde(flatten)]
+    pub self_data: User,
+    pub roles: Option<Vec<Role>>,
+    pub tags: Option<Vec<Tag>>,
+}
+impl UserLazy {
+    pub fn init(self_data: User) -> Self {
+        Self {
+            self_data,
+            roles: None,
+            tags: None,
+        }
+    }
+    pub fn push_or_set_roles(&mut self, val: Role) {
+        if let Some(vec) = self.roles.as_mut() {
+            vec.push(val);
+        } else {
+            self.roles = Some(vec![val]);
+        }
+    }
+    pub fn push_or_set_tags(&mut self, val: Tag) {
+        if let Some(vec) = self.tags.as_mut() {
+            vec.push(val);
+        } else {
+            self.tags = Some(vec![val]);
+        }
+    }
+}
+///Marker of the `roles` relation of [`User`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRolesRelation;
+///Marker of the `tags` relation of [`User`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserTagsRelation;
+impl User {
+    pub const ROLES: UserRolesRelation = UserRolesRelation;
+    pub const TAGS: UserTagsRelation = UserTagsRelation;
+}
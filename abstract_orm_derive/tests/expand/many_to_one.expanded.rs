@@ -0,0 +1,1075 @@
+#[derive(::diesel::Insertable)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[diesel(table_name = posts)]
+pub struct NewPost {
+    pub user_id: i64,
+    pub category_id: Option<i64>,
+    pub title: String,
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct PostWithAll {
+    #[serde(flatten)]
+    pub self_data: Post,
+    pub user: User,
+    pub category: Option<Category>,
+}
+impl PostWithAll {
+    pub fn new_builder(
+        (self_data, user, category): (Post, User, Option<Category>),
+    ) -> Self {
+        Self { self_data, user, category }
+    }
+    pub fn insert_data(
+        &mut self,
+        (self_data, user, category): (Post, User, Option<Category>),
+    ) {}
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::diesel::{
+        BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _,
+        JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+        QueryDsl as _, SelectableHelper as _,
+    };
+    impl Post {
+        pub fn get_table_ref() -> posts::table {
+            return posts::table;
+        }
+        pub fn find_all(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Self> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn).unwrap();
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    posts::table
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select()),
+                    conn,
+                )
+                .unwrap();
+        }
+        pub fn insert(
+            data: NewPost,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<NewPost, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::insert_into(posts::table).values(&data),
+                conn,
+            )?;
+            Ok(data)
+        }
+        pub fn update(
+            &mut self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::update(posts::table.filter(posts::id.eq(&self.id)))
+                    .set((
+                        posts::user_id.eq(&self.user_id),
+                        posts::category_id.eq(&self.category_id),
+                        posts::title.eq(&self.title),
+                    )),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn delete(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::delete(posts::table.filter(posts::id.eq(&self.id))),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn find(
+            uid: i64,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Option<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let result = ::diesel::RunQueryDsl::first::<
+                Self,
+            >(posts::table.filter(posts::id.eq(uid)), conn)
+                .optional()?;
+            Ok(result)
+        }
+        pub fn find_all_eager(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostWithAll>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<PostWithAll> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, User, Option<Category>),
+            >(
+                posts::table
+                    .left_join(
+                        Category::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        Category::get_table_ref(),
+                                    )
+                                    .and(
+                                        <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .inner_join(
+                        User::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        User::get_table_ref(),
+                                    )
+                                    .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((
+                        Self::as_select(),
+                        User::as_select(),
+                        Option::<Category>::as_select(),
+                    )),
+                conn,
+            )?;
+            for query_row in all_rows {
+                let current_id = query_row.0.id;
+                if last_id == current_id {
+                    ret_data.last_mut().unwrap().insert_data(query_row);
+                } else {
+                    let data = PostWithAll::new_builder(query_row);
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_category(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<PostLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Category>),
+            >(
+                posts::table
+                    .left_join(
+                        Category::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        Category::get_table_ref(),
+                                    )
+                                    .and(
+                                        <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), Option::<Category>::as_select())),
+                conn,
+            )?;
+            for (self_data, category) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    if let Some(val) = category {
+                        data.push_or_set_category(val);
+                    }
+                } else {
+                    let mut data = PostLazy::init(self_data);
+                    if let Some(val) = category {
+                        data.push_or_set_category(val);
+                    }
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_user(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<PostLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, User),
+            >(
+                posts::table
+                    .inner_join(
+                        User::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        User::get_table_ref(),
+                                    )
+                                    .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), User::as_select())),
+                conn,
+            )?;
+            for (self_data, user) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    data.push_or_set_user(user);
+                } else {
+                    let mut data = PostLazy::init(self_data);
+                    data.push_or_set_user(user);
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_many(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<Self> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                ret_data
+                    .extend(
+                        ::diesel::RunQueryDsl::load(
+                            posts::table
+                                .filter(posts::id.eq_any(chunk))
+                                .order_by(
+                                    ::abstract_orm::Order::with_primary_key(
+                                        None::<::abstract_orm::Order<Self>>,
+                                    ),
+                                )
+                                .select(Self::as_select()),
+                            conn,
+                        )?,
+                    );
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_map(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<::std::collections::HashMap<i64, Self>, ::diesel::result::Error> {
+            let found = Self::find_many(keys, conn)?;
+            return Ok(found.into_iter().map(|data| (data.id.clone(), data)).collect());
+        }
+        pub fn find_many_with_category(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<PostLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<PostLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, Option<Category>),
+                >(
+                    posts::table
+                        .left_join(
+                            Category::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            Post::get_table_ref(),
+                                            Category::get_table_ref(),
+                                        )
+                                        .and(
+                                            <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                        ),
+                                ),
+                        )
+                        .filter(posts::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), Option::<Category>::as_select())),
+                    conn,
+                )?;
+                for (self_data, category) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        if let Some(val) = category {
+                            data.push_or_set_category(val);
+                        }
+                    } else {
+                        let mut data = PostLazy::init(self_data);
+                        if let Some(val) = category {
+                            data.push_or_set_category(val);
+                        }
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn find_many_with_user(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<PostLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<PostLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, User),
+                >(
+                    posts::table
+                        .inner_join(
+                            User::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            Post::get_table_ref(),
+                                            User::get_table_ref(),
+                                        )
+                                        .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                                ),
+                        )
+                        .filter(posts::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), User::as_select())),
+                    conn,
+                )?;
+                for (self_data, user) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        data.push_or_set_user(user);
+                    } else {
+                        let mut data = PostLazy::init(self_data);
+                        data.push_or_set_user(user);
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn iter_all(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<Self, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let data = ::diesel::RunQueryDsl::load(
+                        posts::table
+                            .filter(posts::id.gt(after))
+                            .order_by(posts::id)
+                            .limit(batch_size as i64)
+                            .select(Self::as_select()),
+                        conn,
+                    )?;
+                    let last_key = data.last().map(|data| data.id);
+                    return Ok(::abstract_orm::Page {
+                        keys: data.len(),
+                        last_key,
+                        data,
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_category(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<PostLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        posts::table
+                            .filter(posts::id.gt(after))
+                            .order_by(posts::id)
+                            .limit(batch_size as i64)
+                            .select(posts::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<PostLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Category>),
+                    >(
+                        posts::table
+                            .left_join(
+                                Category::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Post::get_table_ref(),
+                                                Category::get_table_ref(),
+                                            )
+                                            .and(
+                                                <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .filter(posts::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((
+                                Self::as_select(),
+                                Option::<Category>::as_select(),
+                            )),
+                        conn,
+                    )?;
+                    for (self_data, category) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            if let Some(val) = category {
+                                data.push_or_set_category(val);
+                            }
+                        } else {
+                            let mut data = PostLazy::init(self_data);
+                            if let Some(val) = category {
+                                data.push_or_set_category(val);
+                            }
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_user(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<PostLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        posts::table
+                            .filter(posts::id.gt(after))
+                            .order_by(posts::id)
+                            .limit(batch_size as i64)
+                            .select(posts::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<PostLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, User),
+                    >(
+                        posts::table
+                            .inner_join(
+                                User::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Post::get_table_ref(),
+                                                User::get_table_ref(),
+                                            )
+                                            .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(posts::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((Self::as_select(), User::as_select())),
+                        conn,
+                    )?;
+                    for (self_data, user) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            data.push_or_set_user(user);
+                        } else {
+                            let mut data = PostLazy::init(self_data);
+                            data.push_or_set_user(user);
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_eager(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<PostWithAll, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        posts::table
+                            .filter(posts::id.gt(after))
+                            .order_by(posts::id)
+                            .limit(batch_size as i64)
+                            .select(posts::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<PostWithAll> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, User, Option<Category>),
+                    >(
+                        posts::table
+                            .left_join(
+                                Category::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Post::get_table_ref(),
+                                                Category::get_table_ref(),
+                                            )
+                                            .and(
+                                                <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                            ),
+                                    ),
+                            )
+                            .inner_join(
+                                User::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Post::get_table_ref(),
+                                                User::get_table_ref(),
+                                            )
+                                            .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(posts::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((
+                                Self::as_select(),
+                                User::as_select(),
+                                Option::<Category>::as_select(),
+                            )),
+                        conn,
+                    )?;
+                    for query_row in all_rows {
+                        let current_id = query_row.0.id;
+                        if last_id == current_id {
+                            ret_data.last_mut().unwrap().insert_data(query_row);
+                        } else {
+                            let data = PostWithAll::new_builder(query_row);
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(posts::id.nullable()), conn)?;
+            return Ok(rows.into_iter().map(|(_, data)| data).collect());
+        }
+        pub fn find_all_with_category_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, Option<R>)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<Category>,
+            R: Clone,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(
+                None,
+                Box::new(posts::category_id.nullable()),
+                conn,
+            )?;
+            let fk_values: Vec<i64> = rows
+                .iter()
+                .filter_map(|(fk_value, _)| *fk_value)
+                .collect();
+            let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                    Some(
+                        Box::new(
+                            ::diesel::Table::primary_key(&Category::get_table_ref())
+                                .eq_any(fk_values)
+                                .and(
+                                    <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                )
+                                .nullable(),
+                        ),
+                    ),
+                    Box::new(
+                        ::diesel::Table::primary_key(&Category::get_table_ref())
+                            .nullable(),
+                    ),
+                    conn,
+                )?
+                .into_iter()
+                .collect();
+            let mut ret_data: Vec<(P, Option<R>)> = Vec::new();
+            for (fk_value, data) in rows {
+                ret_data.push((data, related.get(&fk_value).cloned()));
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_all_with_user_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, R)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<User>,
+            R: Clone,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(posts::user_id.nullable()), conn)?;
+            let fk_values: Vec<i64> = rows
+                .iter()
+                .filter_map(|(fk_value, _)| *fk_value)
+                .collect();
+            let related: ::std::collections::HashMap<Option<i64>, R> = R::load_keyed(
+                    Some(
+                        Box::new(
+                            ::diesel::Table::primary_key(&User::get_table_ref())
+                                .eq_any(fk_values)
+                                .and(<User as ::abstract_orm::SoftDelete>::not_deleted())
+                                .nullable(),
+                        ),
+                    ),
+                    Box::new(
+                        ::diesel::Table::primary_key(&User::get_table_ref()).nullable(),
+                    ),
+                    conn,
+                )?
+                .into_iter()
+                .collect();
+            let mut ret_data: Vec<(P, R)> = Vec::new();
+            for (fk_value, data) in rows {
+                if let Some(related_data) = related.get(&fk_value) {
+                    ret_data.push((data, related_data.clone()));
+                }
+            }
+            return Ok(ret_data);
+        }
+        pub fn get_user(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> User {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)
+                .expect("could not get a connection");
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::first::<
+                User,
+            >(
+                    ::diesel::QueryDsl::select(
+                        ::diesel::QueryDsl::find(User::get_table_ref(), self.user_id),
+                        <User as ::diesel::SelectableHelper<
+                            ::diesel::mysql::Mysql,
+                        >>::as_select(),
+                    ),
+                    conn,
+                )
+                .expect("could not fetch relation");
+        }
+        pub fn get_category(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Option<Category> {
+            if let Some(fk_value) = self.category_id {
+                return Category::find(fk_value, conn).expect("could not fetch relation");
+            } else {
+                return None;
+            }
+        }
+        pub fn load_with<R: ::abstract_orm::Relations<Self>>(
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<PostLazy>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy: Vec<PostLazy> = <Self as ::abstract_orm::Entity>::find_all(
+                    conn,
+                )?
+                .into_iter()
+                .map(PostLazy::init)
+                .collect();
+            relations.load_into(&mut lazy, conn)?;
+            return Ok(lazy);
+        }
+        pub fn with_relations<R: ::abstract_orm::Relations<Self>>(
+            self,
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<PostLazy, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy = [PostLazy::init(self)];
+            relations.load_into(&mut lazy, conn)?;
+            let [lazy] = lazy;
+            return Ok(lazy);
+        }
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        fn __assert_relations() {
+            fn relation_needs_joinable<Left, Right>(_: Left, _: Right)
+            where
+                Left: ::diesel::JoinTo<Right>,
+            {}
+            fn relation_needs_allow_tables_to_appear_in_same_query<Left, Right>(
+                _: Left,
+                _: Right,
+            )
+            where
+                Left: ::diesel::query_source::TableNotEqual<Right>,
+                Right: ::diesel::Table,
+            {}
+            fn relation_needs_belongs_to<Child, Parent>()
+            where
+                Child: ::diesel::associations::BelongsTo<Parent>,
+            {}
+            relation_needs_joinable(Self::get_table_ref(), User::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                User::get_table_ref(),
+            );
+            relation_needs_joinable(Self::get_table_ref(), Category::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                Category::get_table_ref(),
+            );
+        }
+    }
+    impl ::abstract_orm::Entity for Post {
+        type Table = posts::table;
+        type Key = i64;
+        type New<'a> = NewPost where Self: 'a;
+        type Lazy = PostLazy;
+        type WithAll = PostWithAll;
+        type Connection = ::diesel::mysql::MysqlConnection;
+        fn get_table_ref() -> Self::Table {
+            return posts::table;
+        }
+        fn find(
+            key: Self::Key,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+            let uid = key;
+            return Post::find(uid, conn).map_err(::abstract_orm::OrmError::from);
+        }
+        fn find_all(
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    posts::table.select(Self::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn insert<'a>(
+            data: Self::New<'a>,
+            conn: &mut Self::Connection,
+        ) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+            return Post::insert(data, conn).map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::SoftDelete for Post {
+        type NotDeleted = ::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>;
+        fn not_deleted() -> Self::NotDeleted {
+            return ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true);
+        }
+    }
+    impl ::abstract_orm::BelongsTo<User> for Post {
+        fn load_parent(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<User>, ::abstract_orm::OrmError> {
+            return <User>::find(self.user_id, conn)
+                .map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::BelongsTo<Category> for Post {
+        fn load_parent(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Category>, ::abstract_orm::OrmError> {
+            return match self.category_id {
+                Some(fk_value) => {
+                    <Category>::find(fk_value, conn)
+                        .map_err(::abstract_orm::OrmError::from)
+                }
+                None => Ok(None),
+            };
+        }
+    }
+    impl ::abstract_orm::Relation<Post> for PostCategoryRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [PostLazy],
+            conn: &mut <Post as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, category) in ::diesel::RunQueryDsl::load::<
+                (i64, Option<Category>),
+            >(
+                posts::table
+                    .left_join(
+                        Category::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        Category::get_table_ref(),
+                                    )
+                                    .and(
+                                        <Category as ::abstract_orm::SoftDelete>::not_deleted(),
+                                    ),
+                            ),
+                    )
+                    .filter(posts::id.eq_any(ids))
+                    .select((posts::id, Option::<Category>::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                if let Some(val) = category {
+                    data.push_or_set_category(val);
+                }
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<Post> for PostCategoryRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [PostLazy],
+            conn: &mut <Post as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+    impl ::abstract_orm::Relation<Post> for PostUserRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [PostLazy],
+            conn: &mut <Post as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, user) in ::diesel::RunQueryDsl::load::<
+                (i64, User),
+            >(
+                posts::table
+                    .inner_join(
+                        User::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Post::get_table_ref(),
+                                        User::get_table_ref(),
+                                    )
+                                    .and(<User as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .filter(posts::id.eq_any(ids))
+                    .select((posts::id, User::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                data.push_or_set_user(user);
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<Post> for PostUserRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [PostLazy],
+            conn: &mut <Post as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+};
+impl ::abstract_orm::Projection<Post> for Post {
+    fn load_keyed(
+        filter: Option<::abstract_orm::ProjectionFilter<Post>>,
+        key: ::abstract_orm::ProjectionKey<Post>,
+        conn: &mut <Post as ::abstract_orm::Entity>::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+        let mut query = ::diesel::QueryDsl::into_boxed::<
+            ::diesel::mysql::Mysql,
+        >(
+            ::diesel::QueryDsl::select(
+                posts::table,
+                (
+                    key,
+                    <Self as ::diesel::SelectableHelper<
+                        ::diesel::mysql::Mysql,
+                    >>::as_select(),
+                ),
+            ),
+        );
+        if let Some(filter) = filter {
+            query = ::diesel::QueryDsl::filter(query, filter);
+        }
+        return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+            .map_err(::abstract_orm::OrmError::from);
+    }
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct PostLazy {
+    #[serde(flatten)]
+    pub self_data: Post,
+    pub user: Option<User>,
+    pub category: Option<Category>,
+}
+impl PostLazy {
+    pub fn init(self_data: Post) -> Self {
+        Self {
+            self_data,
+            user: None,
+            category: None,
+        }
+    }
+    pub fn push_or_set_category(&mut self, val: Category) {
+        self.category = Some(val);
+    }
+    pub fn push_or_set_user(&mut self, val: User) {
+        self.user = Some(val);
+    }
+}
+///Marker of the `category` relation of [`Post`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PostCategoryRelation;
+///Marker of the `user` relation of [`Post`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PostUserRelation;
+impl Post {
+    pub const CATEGORY: PostCategoryRelation = PostCategoryRelation;
+    pub const USER: PostUserRelation = PostUserRelation;
+}
@@ -0,0 +1,1157 @@
+#[derive(::diesel::Insertable)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[diesel(table_name = categories)]
+pub struct NewCategory {
+    pub name: String,
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct CategoryWithAll {
+    #[serde(flatten)]
+    pub self_data: Category,
+    pub posts: Vec<Post>,
+    pub drafts: Vec<Draft>,
+}
+impl CategoryWithAll {
+    pub fn new_builder(
+        (self_data, posts, drafts): (Category, Option<Post>, Option<Draft>),
+    ) -> Self {
+        Self {
+            self_data,
+            posts: if let Some(data) = posts { vec![data] } else { Vec::new() },
+            drafts: if let Some(data) = drafts { vec![data] } else { Vec::new() },
+        }
+    }
+    pub fn insert_data(
+        &mut self,
+        (self_data, posts, drafts): (Category, Option<Post>, Option<Draft>),
+    ) {
+        if let Some(data) = posts {
+            self.posts.push(data);
+        }
+        if let Some(data) = drafts {
+            self.drafts.push(data);
+        }
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::diesel::{
+        BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _,
+        JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+        QueryDsl as _, SelectableHelper as _,
+    };
+    impl Category {
+        pub fn get_table_ref() -> categories::table {
+            return categories::table;
+        }
+        pub fn find_all(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Self> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn).unwrap();
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    categories::table
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select()),
+                    conn,
+                )
+                .unwrap();
+        }
+        pub fn insert(
+            data: NewCategory,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<NewCategory, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::insert_into(categories::table).values(&data),
+                conn,
+            )?;
+            Ok(data)
+        }
+        pub fn update(
+            &mut self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::update(categories::table.filter(categories::id.eq(&self.id)))
+                    .set((categories::name.eq(&self.name),)),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn delete(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::delete(categories::table.filter(categories::id.eq(&self.id))),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn find(
+            uid: i64,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Option<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let result = ::diesel::RunQueryDsl::first::<
+                Self,
+            >(categories::table.filter(categories::id.eq(uid)), conn)
+                .optional()?;
+            Ok(result)
+        }
+        pub fn find_all_eager(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryWithAll>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<CategoryWithAll> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Post>, Option<Draft>),
+            >(
+                categories::table
+                    .left_join(
+                        Draft::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Draft::get_table_ref(),
+                                    )
+                                    .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .left_join(
+                        Post::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Post::get_table_ref(),
+                                    )
+                                    .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((
+                        Self::as_select(),
+                        Option::<Post>::as_select(),
+                        Option::<Draft>::as_select(),
+                    )),
+                conn,
+            )?;
+            for query_row in all_rows {
+                let current_id = query_row.0.id;
+                if last_id == current_id {
+                    ret_data.last_mut().unwrap().insert_data(query_row);
+                } else {
+                    let data = CategoryWithAll::new_builder(query_row);
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            for data in &mut ret_data {
+                ::abstract_orm::sort_children(
+                    &mut data.posts,
+                    |child: &Post| &child.created_at,
+                    true,
+                );
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_drafts(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<CategoryLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Draft>),
+            >(
+                categories::table
+                    .left_join(
+                        Draft::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Draft::get_table_ref(),
+                                    )
+                                    .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), Option::<Draft>::as_select())),
+                conn,
+            )?;
+            for (self_data, drafts) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    if let Some(val) = drafts {
+                        data.push_or_set_drafts(val);
+                    }
+                } else {
+                    let mut data = CategoryLazy::init(self_data);
+                    if let Some(val) = drafts {
+                        data.push_or_set_drafts(val);
+                    }
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_all_with_posts(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<CategoryLazy> = Vec::new();
+            let mut last_id = 0;
+            let all_rows = ::diesel::RunQueryDsl::load::<
+                (Self, Option<Post>),
+            >(
+                categories::table
+                    .left_join(
+                        Post::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Post::get_table_ref(),
+                                    )
+                                    .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .order_by(::abstract_orm::Order::with_primary_key(order))
+                    .select((Self::as_select(), Option::<Post>::as_select())),
+                conn,
+            )?;
+            for (self_data, posts) in all_rows {
+                let current_id = self_data.id;
+                if last_id == current_id {
+                    let data = ret_data.last_mut().unwrap();
+                    if let Some(val) = posts {
+                        data.push_or_set_posts(val);
+                    }
+                } else {
+                    let mut data = CategoryLazy::init(self_data);
+                    if let Some(val) = posts {
+                        data.push_or_set_posts(val);
+                    }
+                    ret_data.push(data);
+                    last_id = current_id;
+                }
+            }
+            for data in &mut ret_data {
+                if let Some(children) = &mut data.posts {
+                    ::abstract_orm::sort_children(
+                        children,
+                        |child: &Post| &child.created_at,
+                        true,
+                    );
+                }
+            }
+            Ok(ret_data)
+        }
+        pub fn find_many(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<Self> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                ret_data
+                    .extend(
+                        ::diesel::RunQueryDsl::load(
+                            categories::table
+                                .filter(categories::id.eq_any(chunk))
+                                .order_by(
+                                    ::abstract_orm::Order::with_primary_key(
+                                        None::<::abstract_orm::Order<Self>>,
+                                    ),
+                                )
+                                .select(Self::as_select()),
+                            conn,
+                        )?,
+                    );
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_map(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<::std::collections::HashMap<i64, Self>, ::diesel::result::Error> {
+            let found = Self::find_many(keys, conn)?;
+            return Ok(found.into_iter().map(|data| (data.id.clone(), data)).collect());
+        }
+        pub fn find_many_with_drafts(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<CategoryLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<CategoryLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, Option<Draft>),
+                >(
+                    categories::table
+                        .left_join(
+                            Draft::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            Category::get_table_ref(),
+                                            Draft::get_table_ref(),
+                                        )
+                                        .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                                ),
+                        )
+                        .filter(categories::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), Option::<Draft>::as_select())),
+                    conn,
+                )?;
+                for (self_data, drafts) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        if let Some(val) = drafts {
+                            data.push_or_set_drafts(val);
+                        }
+                    } else {
+                        let mut data = CategoryLazy::init(self_data);
+                        if let Some(val) = drafts {
+                            data.push_or_set_drafts(val);
+                        }
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn find_many_with_posts(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryLazy>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut all_data: Vec<CategoryLazy> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                let mut ret_data: Vec<CategoryLazy> = Vec::new();
+                let mut last_id = 0;
+                let all_rows = ::diesel::RunQueryDsl::load::<
+                    (Self, Option<Post>),
+                >(
+                    categories::table
+                        .left_join(
+                            Post::get_table_ref()
+                                .on(
+                                    ::abstract_orm::join_on(
+                                            Category::get_table_ref(),
+                                            Post::get_table_ref(),
+                                        )
+                                        .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                                ),
+                        )
+                        .filter(categories::id.eq_any(chunk))
+                        .order_by(
+                            ::abstract_orm::Order::with_primary_key(
+                                None::<::abstract_orm::Order<Self>>,
+                            ),
+                        )
+                        .select((Self::as_select(), Option::<Post>::as_select())),
+                    conn,
+                )?;
+                for (self_data, posts) in all_rows {
+                    let current_id = self_data.id;
+                    if last_id == current_id {
+                        let data = ret_data.last_mut().unwrap();
+                        if let Some(val) = posts {
+                            data.push_or_set_posts(val);
+                        }
+                    } else {
+                        let mut data = CategoryLazy::init(self_data);
+                        if let Some(val) = posts {
+                            data.push_or_set_posts(val);
+                        }
+                        ret_data.push(data);
+                        last_id = current_id;
+                    }
+                }
+                for data in &mut ret_data {
+                    if let Some(children) = &mut data.posts {
+                        ::abstract_orm::sort_children(
+                            children,
+                            |child: &Post| &child.created_at,
+                            true,
+                        );
+                    }
+                }
+                all_data.extend(ret_data);
+            }
+            return Ok(all_data);
+        }
+        pub fn iter_all(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<Self, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let data = ::diesel::RunQueryDsl::load(
+                        categories::table
+                            .filter(categories::id.gt(after))
+                            .order_by(categories::id)
+                            .limit(batch_size as i64)
+                            .select(Self::as_select()),
+                        conn,
+                    )?;
+                    let last_key = data.last().map(|data| data.id);
+                    return Ok(::abstract_orm::Page {
+                        keys: data.len(),
+                        last_key,
+                        data,
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_drafts(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<CategoryLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        categories::table
+                            .filter(categories::id.gt(after))
+                            .order_by(categories::id)
+                            .limit(batch_size as i64)
+                            .select(categories::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<CategoryLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Draft>),
+                    >(
+                        categories::table
+                            .left_join(
+                                Draft::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Category::get_table_ref(),
+                                                Draft::get_table_ref(),
+                                            )
+                                            .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(categories::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((Self::as_select(), Option::<Draft>::as_select())),
+                        conn,
+                    )?;
+                    for (self_data, drafts) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            if let Some(val) = drafts {
+                                data.push_or_set_drafts(val);
+                            }
+                        } else {
+                            let mut data = CategoryLazy::init(self_data);
+                            if let Some(val) = drafts {
+                                data.push_or_set_drafts(val);
+                            }
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_with_posts(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<CategoryLazy, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        categories::table
+                            .filter(categories::id.gt(after))
+                            .order_by(categories::id)
+                            .limit(batch_size as i64)
+                            .select(categories::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<CategoryLazy> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Post>),
+                    >(
+                        categories::table
+                            .left_join(
+                                Post::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Category::get_table_ref(),
+                                                Post::get_table_ref(),
+                                            )
+                                            .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(categories::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((Self::as_select(), Option::<Post>::as_select())),
+                        conn,
+                    )?;
+                    for (self_data, posts) in all_rows {
+                        let current_id = self_data.id;
+                        if last_id == current_id {
+                            let data = ret_data.last_mut().unwrap();
+                            if let Some(val) = posts {
+                                data.push_or_set_posts(val);
+                            }
+                        } else {
+                            let mut data = CategoryLazy::init(self_data);
+                            if let Some(val) = posts {
+                                data.push_or_set_posts(val);
+                            }
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    for data in &mut ret_data {
+                        if let Some(children) = &mut data.posts {
+                            ::abstract_orm::sort_children(
+                                children,
+                                |child: &Post| &child.created_at,
+                                true,
+                            );
+                        }
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn iter_all_eager(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<
+            Item = Result<CategoryWithAll, ::diesel::result::Error>,
+        > + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let keys = ::diesel::RunQueryDsl::load::<
+                        i64,
+                    >(
+                        categories::table
+                            .filter(categories::id.gt(after))
+                            .order_by(categories::id)
+                            .limit(batch_size as i64)
+                            .select(categories::id),
+                        conn,
+                    )?;
+                    let mut ret_data: Vec<CategoryWithAll> = Vec::new();
+                    let mut last_id = 0;
+                    let all_rows = ::diesel::RunQueryDsl::load::<
+                        (Self, Option<Post>, Option<Draft>),
+                    >(
+                        categories::table
+                            .left_join(
+                                Draft::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Category::get_table_ref(),
+                                                Draft::get_table_ref(),
+                                            )
+                                            .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .left_join(
+                                Post::get_table_ref()
+                                    .on(
+                                        ::abstract_orm::join_on(
+                                                Category::get_table_ref(),
+                                                Post::get_table_ref(),
+                                            )
+                                            .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                                    ),
+                            )
+                            .filter(categories::id.eq_any(&keys))
+                            .order_by(
+                                ::abstract_orm::Order::with_primary_key(
+                                    None::<::abstract_orm::Order<Self>>,
+                                ),
+                            )
+                            .select((
+                                Self::as_select(),
+                                Option::<Post>::as_select(),
+                                Option::<Draft>::as_select(),
+                            )),
+                        conn,
+                    )?;
+                    for query_row in all_rows {
+                        let current_id = query_row.0.id;
+                        if last_id == current_id {
+                            ret_data.last_mut().unwrap().insert_data(query_row);
+                        } else {
+                            let data = CategoryWithAll::new_builder(query_row);
+                            ret_data.push(data);
+                            last_id = current_id;
+                        }
+                    }
+                    for data in &mut ret_data {
+                        ::abstract_orm::sort_children(
+                            &mut data.posts,
+                            |child: &Post| &child.created_at,
+                            true,
+                        );
+                    }
+                    return Ok(::abstract_orm::Page {
+                        data: ret_data,
+                        last_key: keys.last().copied(),
+                        keys: keys.len(),
+                    });
+                },
+            );
+        }
+        pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(categories::id.nullable()), conn)?;
+            return Ok(rows.into_iter().map(|(_, data)| data).collect());
+        }
+        pub fn find_all_with_drafts_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, Vec<R>)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<Draft>,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(categories::id.nullable()), conn)?;
+            let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+            let foreign_key = <Draft as ::diesel::associations::BelongsTo<
+                Self,
+            >>::foreign_key_column();
+            let related = R::load_keyed(
+                Some(
+                    Box::new(
+                        foreign_key
+                            .eq_any(ids)
+                            .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted())
+                            .nullable(),
+                    ),
+                ),
+                Box::new(foreign_key.nullable()),
+                conn,
+            )?;
+            let mut positions: ::std::collections::HashMap<Option<i64>, usize> = ::std::collections::HashMap::new();
+            let mut ret_data: Vec<(P, Vec<R>)> = Vec::new();
+            for (id, data) in rows {
+                positions.insert(id, ret_data.len());
+                ret_data.push((data, Vec::new()));
+            }
+            for (fk_value, related_data) in related {
+                if let Some(position) = positions.get(&fk_value) {
+                    ret_data[*position].1.push(related_data);
+                }
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_all_with_posts_as<P, R>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<(P, Vec<R>)>, ::abstract_orm::OrmError>
+        where
+            P: ::abstract_orm::Projection<Self>,
+            R: ::abstract_orm::Projection<Post>,
+        {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(categories::id.nullable()), conn)?;
+            let ids: Vec<i64> = rows.iter().filter_map(|(id, _)| *id).collect();
+            let foreign_key = <Post as ::diesel::associations::BelongsTo<
+                Self,
+            >>::foreign_key_column();
+            let related = R::load_keyed(
+                Some(
+                    Box::new(
+                        foreign_key
+                            .eq_any(ids)
+                            .and(<Post as ::abstract_orm::SoftDelete>::not_deleted())
+                            .nullable(),
+                    ),
+                ),
+                Box::new(foreign_key.nullable()),
+                conn,
+            )?;
+            let mut positions: ::std::collections::HashMap<Option<i64>, usize> = ::std::collections::HashMap::new();
+            let mut ret_data: Vec<(P, Vec<R>)> = Vec::new();
+            for (id, data) in rows {
+                positions.insert(id, ret_data.len());
+                ret_data.push((data, Vec::new()));
+            }
+            for (fk_value, related_data) in related {
+                if let Some(position) = positions.get(&fk_value) {
+                    ret_data[*position].1.push(related_data);
+                }
+            }
+            return Ok(ret_data);
+        }
+        pub fn get_posts(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Post> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)
+                .expect("could not get a connection");
+            let conn = &mut *conn;
+            return {
+                let mut children = ::diesel::RunQueryDsl::load(
+                        Post::belonging_to(&self)
+                            .filter(<Post as ::abstract_orm::SoftDelete>::not_deleted())
+                            .select(Post::as_select()),
+                        conn,
+                    )
+                    .expect("error fetching #ident from #type_name");
+                ::abstract_orm::sort_children(
+                    &mut children,
+                    |child: &Post| &child.created_at,
+                    true,
+                );
+                children
+            };
+        }
+        pub fn get_drafts(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Draft> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)
+                .expect("could not get a connection");
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    Draft::belonging_to(&self)
+                        .filter(<Draft as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Draft::as_select()),
+                    conn,
+                )
+                .expect("error fetching #ident from #type_name");
+        }
+        pub fn load_with<R: ::abstract_orm::Relations<Self>>(
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<CategoryLazy>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy: Vec<CategoryLazy> = <Self as ::abstract_orm::Entity>::find_all(
+                    conn,
+                )?
+                .into_iter()
+                .map(CategoryLazy::init)
+                .collect();
+            relations.load_into(&mut lazy, conn)?;
+            return Ok(lazy);
+        }
+        pub fn with_relations<R: ::abstract_orm::Relations<Self>>(
+            self,
+            relations: R,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<CategoryLazy, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut lazy = [CategoryLazy::init(self)];
+            relations.load_into(&mut lazy, conn)?;
+            let [lazy] = lazy;
+            return Ok(lazy);
+        }
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        fn __assert_relations() {
+            fn relation_needs_joinable<Left, Right>(_: Left, _: Right)
+            where
+                Left: ::diesel::JoinTo<Right>,
+            {}
+            fn relation_needs_allow_tables_to_appear_in_same_query<Left, Right>(
+                _: Left,
+                _: Right,
+            )
+            where
+                Left: ::diesel::query_source::TableNotEqual<Right>,
+                Right: ::diesel::Table,
+            {}
+            fn relation_needs_belongs_to<Child, Parent>()
+            where
+                Child: ::diesel::associations::BelongsTo<Parent>,
+            {}
+            relation_needs_joinable(Self::get_table_ref(), Post::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                Post::get_table_ref(),
+            );
+            relation_needs_belongs_to::<Post, Self>();
+            relation_needs_joinable(Self::get_table_ref(), Draft::get_table_ref());
+            relation_needs_allow_tables_to_appear_in_same_query(
+                Self::get_table_ref(),
+                Draft::get_table_ref(),
+            );
+            relation_needs_belongs_to::<Draft, Self>();
+        }
+    }
+    impl ::abstract_orm::Entity for Category {
+        type Table = categories::table;
+        type Key = i64;
+        type New<'a> = NewCategory where Self: 'a;
+        type Lazy = CategoryLazy;
+        type WithAll = CategoryWithAll;
+        type Connection = ::diesel::mysql::MysqlConnection;
+        fn get_table_ref() -> Self::Table {
+            return categories::table;
+        }
+        fn find(
+            key: Self::Key,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+            let uid = key;
+            return Category::find(uid, conn).map_err(::abstract_orm::OrmError::from);
+        }
+        fn find_all(
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    categories::table.select(Self::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn insert<'a>(
+            data: Self::New<'a>,
+            conn: &mut Self::Connection,
+        ) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+            return Category::insert(data, conn).map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::SoftDelete for Category {
+        type NotDeleted = ::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>;
+        fn not_deleted() -> Self::NotDeleted {
+            return ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true);
+        }
+    }
+    impl ::abstract_orm::HasMany<Post> for Category {
+        fn load_children(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Post>, ::abstract_orm::OrmError> {
+            return Ok({
+                let mut children = ::diesel::RunQueryDsl::load(
+                        Post::belonging_to(&self)
+                            .filter(<Post as ::abstract_orm::SoftDelete>::not_deleted())
+                            .select(Post::as_select()),
+                        conn,
+                    )
+                    .map_err(::abstract_orm::OrmError::from)?;
+                ::abstract_orm::sort_children(
+                    &mut children,
+                    |child: &Post| &child.created_at,
+                    true,
+                );
+                children
+            });
+        }
+    }
+    impl ::abstract_orm::HasMany<Draft> for Category {
+        fn load_children(
+            &self,
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Draft>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    Draft::belonging_to(&self)
+                        .filter(<Draft as ::abstract_orm::SoftDelete>::not_deleted())
+                        .select(Draft::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+    }
+    impl ::abstract_orm::Relation<Category> for CategoryDraftsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [CategoryLazy],
+            conn: &mut <Category as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, drafts) in ::diesel::RunQueryDsl::load::<
+                (i64, Option<Draft>),
+            >(
+                categories::table
+                    .left_join(
+                        Draft::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Draft::get_table_ref(),
+                                    )
+                                    .and(<Draft as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .filter(categories::id.eq_any(ids))
+                    .select((categories::id, Option::<Draft>::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                if let Some(val) = drafts {
+                    data.push_or_set_drafts(val);
+                }
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<Category> for CategoryDraftsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [CategoryLazy],
+            conn: &mut <Category as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+    impl ::abstract_orm::Relation<Category> for CategoryPostsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [CategoryLazy],
+            conn: &mut <Category as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let positions: ::std::collections::HashMap<i64, usize> = lazy
+                .iter()
+                .enumerate()
+                .map(|(position, data)| (data.self_data.id, position))
+                .collect();
+            let ids: Vec<i64> = positions.keys().copied().collect();
+            if ids.len() == 0 {
+                return Ok(());
+            }
+            for (id, posts) in ::diesel::RunQueryDsl::load::<
+                (i64, Option<Post>),
+            >(
+                categories::table
+                    .left_join(
+                        Post::get_table_ref()
+                            .on(
+                                ::abstract_orm::join_on(
+                                        Category::get_table_ref(),
+                                        Post::get_table_ref(),
+                                    )
+                                    .and(<Post as ::abstract_orm::SoftDelete>::not_deleted()),
+                            ),
+                    )
+                    .filter(categories::id.eq_any(ids))
+                    .select((categories::id, Option::<Post>::as_select())),
+                conn,
+            )? {
+                let data = &mut lazy[positions[&id]];
+                if let Some(val) = posts {
+                    data.push_or_set_posts(val);
+                }
+            }
+            for data in lazy.iter_mut() {
+                if let Some(children) = &mut data.posts {
+                    ::abstract_orm::sort_children(
+                        children,
+                        |child: &Post| &child.created_at,
+                        true,
+                    );
+                }
+            }
+            return Ok(());
+        }
+    }
+    impl ::abstract_orm::Relations<Category> for CategoryPostsRelation {
+        fn load_into(
+            &self,
+            lazy: &mut [CategoryLazy],
+            conn: &mut <Category as ::abstract_orm::Entity>::Connection,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            return ::abstract_orm::Relation::load_into(self, lazy, conn);
+        }
+    }
+};
+impl ::abstract_orm::Projection<Category> for Category {
+    fn load_keyed(
+        filter: Option<::abstract_orm::ProjectionFilter<Category>>,
+        key: ::abstract_orm::ProjectionKey<Category>,
+        conn: &mut <Category as ::abstract_orm::Entity>::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+        let mut query = ::diesel::QueryDsl::into_boxed::<
+            ::diesel::mysql::Mysql,
+        >(
+            ::diesel::QueryDsl::select(
+                categories::table,
+                (
+                    key,
+                    <Self as ::diesel::SelectableHelper<
+                        ::diesel::mysql::Mysql,
+                    >>::as_select(),
+                ),
+            ),
+        );
+        if let Some(filter) = filter {
+            query = ::diesel::QueryDsl::filter(query, filter);
+        }
+        return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+            .map_err(::abstract_orm::OrmError::from);
+    }
+}
+#[derive(::serde::Serialize, Clone)]
+pub struct CategoryLazy {
+    #[serde(flatten)]
+    pub self_data: Category,
+    pub posts: Option<Vec<Post>>,
+    pub drafts: Option<Vec<Draft>>,
+}
+impl CategoryLazy {
+    pub fn init(self_data: Category) -> Self {
+        Self {
+            self_data,
+            posts: None,
+            drafts: None,
+        }
+    }
+    pub fn push_or_set_drafts(&mut self, val: Draft) {
+        if let Some(vec) = self.drafts.as_mut() {
+            vec.push(val);
+        } else {
+            self.drafts = Some(vec![val]);
+        }
+    }
+    pub fn push_or_set_posts(&mut self, val: Post) {
+        if let Some(vec) = self.posts.as_mut() {
+            vec.push(val);
+        } else {
+            self.posts = Some(vec![val]);
+        }
+    }
+}
+///Marker of the `drafts` relation of [`Category`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CategoryDraftsRelation;
+///Marker of the `posts` relation of [`Category`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CategoryPostsRelation;
+impl Category {
+    pub const DRAFTS: CategoryDraftsRelation = CategoryDraftsRelation;
+    pub const POSTS: CategoryPostsRelation = CategoryPostsRelation;
+}
@@ -0,0 +1,362 @@
+#[derive(::diesel::Insertable)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[diesel(table_name = accounts)]
+pub struct NewAccount<'a> {
+    pub email: &'a str,
+    pub name: Option<&'a str>,
+    pub age: i32,
+}
+impl Account {
+    /// Checks the `#[validate(...)]` rules, returning every field that fails one.
+    pub fn validate(&self) -> Result<(), Vec<::abstract_orm::FieldError>> {
+        let mut errors: Vec<::abstract_orm::FieldError> = Vec::new();
+        {
+            let value = &self.email;
+            if let Some(message) = ::abstract_orm::check_email(
+                ::std::convert::AsRef::<str>::as_ref(value),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "email",
+                        message,
+                    });
+            }
+            if let Some(message) = ::abstract_orm::check_length(
+                ::std::convert::AsRef::<str>::as_ref(value),
+                None,
+                Some(64),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "email",
+                        message,
+                    });
+            }
+        }
+        {
+            let value = &self.name;
+            if let Some(message) = ::abstract_orm::check_length(
+                ::std::convert::AsRef::<str>::as_ref(value),
+                Some(2),
+                None,
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "name",
+                        message,
+                    });
+            }
+        }
+        {
+            let value = &self.age;
+            if let Some(message) = ::abstract_orm::check_range(
+                value,
+                Some(0),
+                Some(150),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "age",
+                        message,
+                    });
+            }
+        }
+        if errors.len() > 0 {
+            return Err(errors);
+        }
+        return Ok(());
+    }
+}
+impl<'a> NewAccount<'a> {
+    /// Checks the `#[validate(...)]` rules, returning every field that fails one.
+    pub fn validate(&self) -> Result<(), Vec<::abstract_orm::FieldError>> {
+        let mut errors: Vec<::abstract_orm::FieldError> = Vec::new();
+        {
+            let value = &self.email;
+            if let Some(message) = ::abstract_orm::check_email(
+                ::std::convert::AsRef::<str>::as_ref(value),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "email",
+                        message,
+                    });
+            }
+            if let Some(message) = ::abstract_orm::check_length(
+                ::std::convert::AsRef::<str>::as_ref(value),
+                None,
+                Some(64),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "email",
+                        message,
+                    });
+            }
+        }
+        if let Some(value) = &self.name {
+            if let Some(message) = ::abstract_orm::check_length(
+                ::std::convert::AsRef::<str>::as_ref(value),
+                Some(2),
+                None,
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "name",
+                        message,
+                    });
+            }
+        }
+        {
+            let value = &self.age;
+            if let Some(message) = ::abstract_orm::check_range(
+                value,
+                Some(0),
+                Some(150),
+            ) {
+                errors
+                    .push(::abstract_orm::FieldError {
+                        field: "age",
+                        message,
+                    });
+            }
+        }
+        if errors.len() > 0 {
+            return Err(errors);
+        }
+        return Ok(());
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::diesel::{
+        BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _,
+        JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+        QueryDsl as _, SelectableHelper as _,
+    };
+    impl Account {
+        pub fn get_table_ref() -> accounts::table {
+            return accounts::table;
+        }
+        pub fn find_all(
+            order: Option<::abstract_orm::Order<Self>>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Vec<Self> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn).unwrap();
+            let conn = &mut *conn;
+            return ::diesel::RunQueryDsl::load(
+                    accounts::table
+                        .order_by(::abstract_orm::Order::with_primary_key(order))
+                        .select(Self::as_select()),
+                    conn,
+                )
+                .unwrap();
+        }
+        pub fn insert<'a>(
+            data: NewAccount<'a>,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<NewAccount<'a>, ::abstract_orm::OrmError> {
+            data.validate().map_err(::abstract_orm::OrmError::Validation)?;
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::insert_into(accounts::table).values(&data),
+                conn,
+            )?;
+            Ok(data)
+        }
+        pub fn update(
+            &mut self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            self.validate().map_err(::abstract_orm::OrmError::Validation)?;
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::update(accounts::table.filter(accounts::id.eq(&self.id)))
+                    .set((
+                        accounts::email.eq(&self.email),
+                        accounts::name.eq(&self.name),
+                        accounts::age.eq(&self.age),
+                    )),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn delete(
+            &self,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<(), ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::writer(conn)?;
+            let conn = &mut *conn;
+            ::diesel::RunQueryDsl::execute(
+                ::diesel::delete(accounts::table.filter(accounts::id.eq(&self.id))),
+                conn,
+            )?;
+            Ok(())
+        }
+        pub fn find(
+            uid: i64,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Option<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let result = ::diesel::RunQueryDsl::first::<
+                Self,
+            >(accounts::table.filter(accounts::id.eq(uid)), conn)
+                .optional()?;
+            Ok(result)
+        }
+        pub fn find_many(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<Self>, ::diesel::result::Error> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let mut ret_data: Vec<Self> = Vec::new();
+            for chunk in keys.chunks(::abstract_orm::FIND_MANY_CHUNK_SIZE) {
+                ret_data
+                    .extend(
+                        ::diesel::RunQueryDsl::load(
+                            accounts::table
+                                .filter(accounts::id.eq_any(chunk))
+                                .order_by(
+                                    ::abstract_orm::Order::with_primary_key(
+                                        None::<::abstract_orm::Order<Self>>,
+                                    ),
+                                )
+                                .select(Self::as_select()),
+                            conn,
+                        )?,
+                    );
+            }
+            return Ok(ret_data);
+        }
+        pub fn find_map(
+            keys: &[i64],
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<::std::collections::HashMap<i64, Self>, ::diesel::result::Error> {
+            let found = Self::find_many(keys, conn)?;
+            return Ok(found.into_iter().map(|data| (data.id.clone(), data)).collect());
+        }
+        pub fn iter_all(
+            batch_size: usize,
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> impl Iterator<Item = Result<Self, ::diesel::result::Error>> + '_ {
+            return ::abstract_orm::Batches::new(
+                conn,
+                batch_size,
+                move |
+                    after: i64,
+                    conn: &mut <Self as ::abstract_orm::Entity>::Connection|
+                {
+                    let data = ::diesel::RunQueryDsl::load(
+                        accounts::table
+                            .filter(accounts::id.gt(after))
+                            .order_by(accounts::id)
+                            .limit(batch_size as i64)
+                            .select(Self::as_select()),
+                        conn,
+                    )?;
+                    let last_key = data.last().map(|data| data.id);
+                    return Ok(::abstract_orm::Page {
+                        keys: data.len(),
+                        last_key,
+                        data,
+                    });
+                },
+            );
+        }
+        pub fn find_all_as<P: ::abstract_orm::Projection<Self>>(
+            conn: &mut impl ::abstract_orm::ConnectionSource<
+                ::diesel::mysql::MysqlConnection,
+            >,
+        ) -> Result<Vec<P>, ::abstract_orm::OrmError> {
+            let mut conn = ::abstract_orm::ConnectionSource::reader(conn)?;
+            let conn = &mut *conn;
+            let rows = P::load_keyed(None, Box::new(accounts::id.nullable()), conn)?;
+            return Ok(rows.into_iter().map(|(_, data)| data).collect());
+        }
+    }
+    impl ::abstract_orm::Entity for Account {
+        type Table = accounts::table;
+        type Key = i64;
+        type New<'a> = NewAccount<'a> where Self: 'a;
+        type Lazy = Self;
+        type WithAll = Self;
+        type Connection = ::diesel::mysql::MysqlConnection;
+        fn get_table_ref() -> Self::Table {
+            return accounts::table;
+        }
+        fn find(
+            key: Self::Key,
+            conn: &mut Self::Connection,
+        ) -> Result<Option<Self>, ::abstract_orm::OrmError> {
+            let uid = key;
+            return Account::find(uid, conn).map_err(::abstract_orm::OrmError::from);
+        }
+        fn find_all(
+            conn: &mut Self::Connection,
+        ) -> Result<Vec<Self>, ::abstract_orm::OrmError> {
+            return ::diesel::RunQueryDsl::load(
+                    accounts::table.select(Self::as_select()),
+                    conn,
+                )
+                .map_err(::abstract_orm::OrmError::from);
+        }
+        fn insert<'a>(
+            data: Self::New<'a>,
+            conn: &mut Self::Connection,
+        ) -> Result<Self::New<'a>, ::abstract_orm::OrmError> {
+            return Account::insert(data, conn);
+        }
+    }
+    impl ::abstract_orm::SoftDelete for Account {
+        type NotDeleted = ::diesel::dsl::AsExprOf<bool, ::diesel::sql_types::Bool>;
+        fn not_deleted() -> Self::NotDeleted {
+            return ::diesel::IntoSql::into_sql::<::diesel::sql_types::Bool>(true);
+        }
+    }
+};
+impl ::abstract_orm::Projection<Account> for Account {
+    fn load_keyed(
+        filter: Option<::abstract_orm::ProjectionFilter<Account>>,
+        key: ::abstract_orm::ProjectionKey<Account>,
+        conn: &mut <Account as ::abstract_orm::Entity>::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, ::abstract_orm::OrmError> {
+        let mut query = ::diesel::QueryDsl::into_boxed::<
+            ::diesel::mysql::Mysql,
+        >(
+            ::diesel::QueryDsl::select(
+                accounts::table,
+                (
+                    key,
+                    <Self as ::diesel::SelectableHelper<
+                        ::diesel::mysql::Mysql,
+                    >>::as_select(),
+                ),
+            ),
+        );
+        if let Some(filter) = filter {
+            query = ::diesel::QueryDsl::filter(query, filter);
+        }
+        return ::diesel::RunQueryDsl::load::<(Option<i64>, Self)>(query, conn)
+            .map_err(::abstract_orm::OrmError::from);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::ConnectionSource;
+use diesel::result::Error;
+use diesel::Connection;
+use std::marker::PhantomData;
+
+/// Iterator over a whole table a page of `batch_size` entities at a time,
+/// returned by the generated `iter_all`, `iter_all_with_x` and
+/// `iter_all_eager`.
+///
+/// Pages follow the last `id` of the keys of the previous one instead of
+/// an offset, and `load` is handed that `id` to fetch the next page, so a
+/// `XLazy` or `XWithAll` is never split between two pages. Each page is
+/// read from its own `reader` connection of the source `S`.
+///
+/// ```ignore
+/// for user in User::iter_all(500, conn) {
+///     export(user?);
+/// }
+/// ```
+pub struct Batches<'c, S, C, T, F> {
+    conn: &'c mut S,
+    batch_size: usize,
+    load: F,
+    after: i64,
+    page: std::vec::IntoIter<T>,
+    done: bool,
+    connection: PhantomData<fn(&mut C)>,
+}
+
+/// One page loaded for `Batches`, `keys` is the number of `id` it was
+/// asked for up to `last_key`, the entities dropped by a join leave
+/// `data` shorter than that.
+#[doc(hidden)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub last_key: Option<i64>,
+    pub keys: usize,
+}
+
+impl<'c, S, C, T, F> Batches<'c, S, C, T, F>
+where
+    S: ConnectionSource<C>,
+    C: Connection,
+    F: FnMut(i64, &mut C) -> Result<Page<T>, Error>,
+{
+    /// `load` is the page of the entities with an `id` above the one it is given.
+    #[doc(hidden)]
+    pub fn new(conn: &'c mut S, batch_size: usize, load: F) -> Self {
+        Batches {
+            conn,
+            batch_size,
+            load,
+            after: i64::MIN,
+            page: Vec::new().into_iter(),
+            done: batch_size == 0,
+            connection: PhantomData,
+        }
+    }
+
+    fn load_page(&mut self) -> Result<Page<T>, Error> {
+        let mut conn = self.conn.reader()?;
+        (self.load)(self.after, &mut conn)
+    }
+}
+
+impl<S, C, T, F> Iterator for Batches<'_, S, C, T, F>
+where
+    S: ConnectionSource<C>,
+    C: Connection,
+    F: FnMut(i64, &mut C) -> Result<Page<T>, Error>,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(data) = self.page.next() {
+                return Some(Ok(data));
+            }
+            if self.done {
+                return None;
+            }
+            match self.load_page() {
+                Ok(page) => {
+                    self.done = page.keys < self.batch_size;
+                    match page.last_key {
+                        Some(last_key) => self.after = last_key,
+                        None => self.done = true,
+                    }
+                    self.page = page.data.into_iter();
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
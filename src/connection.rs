@@ -0,0 +1,99 @@
+use diesel::result::Error;
+use diesel::Connection;
+use std::ops::DerefMut;
+
+/// Where the sync generated methods get their `C` connection from, reads
+/// go to `reader` and inserts, updates and deletes to `writer`.
+///
+/// Every diesel connection is a source handing out itself for both, so a
+/// `&mut MysqlConnection` can be passed wherever a source is expected. With
+/// the `r2d2` feature, so is a pooled connection, and `ReplicaPools` sends
+/// reads to a replica pool.
+///
+/// Only the inherent methods the derive generates on the entity take a
+/// source. The `Entity`, `HasMany`, `BelongsTo` and `Relation` trait
+/// methods and the `_async` methods take the connection itself and run
+/// everything on it.
+pub trait ConnectionSource<C: Connection> {
+    type Guard<'s>: DerefMut<Target = C>
+    where
+        Self: 's;
+
+    fn reader(&mut self) -> Result<Self::Guard<'_>, Error>;
+
+    fn writer(&mut self) -> Result<Self::Guard<'_>, Error>;
+}
+
+impl<C: Connection> ConnectionSource<C> for C {
+    type Guard<'s>
+        = &'s mut C
+    where
+        C: 's;
+
+    fn reader(&mut self) -> Result<&mut C, Error> {
+        Ok(self)
+    }
+
+    fn writer(&mut self) -> Result<&mut C, Error> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "r2d2")]
+pub use self::pools::ReplicaPools;
+
+#[cfg(feature = "r2d2")]
+mod pools {
+    use super::ConnectionSource;
+    use diesel::r2d2::{ConnectionManager, Pool, PoolError, PooledConnection, R2D2Connection};
+    use diesel::result::{DatabaseErrorKind, Error};
+
+    /// A `primary` pool taking the writes and a `replica` one the reads, the
+    /// same pool can be given twice to route nothing.
+    ///
+    /// ```ignore
+    /// let mut pools = ReplicaPools::new(primary, replica);
+    /// let user = User::find(1, &mut pools)?;
+    /// ```
+    #[derive(Clone)]
+    pub struct ReplicaPools<C: R2D2Connection + 'static> {
+        pub primary: Pool<ConnectionManager<C>>,
+        pub replica: Pool<ConnectionManager<C>>,
+    }
+
+    impl<C: R2D2Connection + 'static> ReplicaPools<C> {
+        pub fn new(primary: Pool<ConnectionManager<C>>, replica: Pool<ConnectionManager<C>>) -> Self {
+            ReplicaPools { primary, replica }
+        }
+    }
+
+    /// A pool that gave no connection in time, as the `UnableToSendCommand`
+    /// error of the query that needed it.
+    fn checkout_error(err: PoolError) -> Error {
+        Error::DatabaseError(DatabaseErrorKind::UnableToSendCommand, Box::new(err.to_string()))
+    }
+
+    impl<C: R2D2Connection + 'static> ConnectionSource<C> for ReplicaPools<C> {
+        type Guard<'s> = PooledConnection<ConnectionManager<C>>;
+
+        fn reader(&mut self) -> Result<Self::Guard<'_>, Error> {
+            self.replica.get().map_err(checkout_error)
+        }
+
+        fn writer(&mut self) -> Result<Self::Guard<'_>, Error> {
+            self.primary.get().map_err(checkout_error)
+        }
+    }
+
+    impl<C: R2D2Connection + 'static> ConnectionSource<C> for PooledConnection<ConnectionManager<C>> {
+        type Guard<'s> = &'s mut C;
+
+        fn reader(&mut self) -> Result<&mut C, Error> {
+            Ok(self)
+        }
+
+        fn writer(&mut self) -> Result<&mut C, Error> {
+            Ok(self)
+        }
+    }
+}
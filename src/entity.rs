@@ -0,0 +1,49 @@
+use crate::OrmError;
+
+/// A table backed model, implemented by `#[derive(Joinable)]`.
+pub trait Entity: Sized {
+    /// The diesel `table` the entity is loaded from.
+    type Table: diesel::Table;
+    /// The primary key, a tuple in field name order for composite keys.
+    type Key;
+    /// The generated `NewX` insertable, borrowing for `'a` with `#[orm(borrowed_insert)]`.
+    type New<'a> where Self: 'a;
+    /// The generated `XLazy`, `Self` for an entity without relations.
+    type Lazy;
+    /// The generated `XWithAll`, `Self` for an entity without relations.
+    type WithAll;
+    /// The connection set with `#[orm(backend = ...)]`.
+    type Connection: diesel::Connection;
+
+    fn get_table_ref() -> Self::Table;
+
+    /// The row with the given primary key, `None` if there is none.
+    fn find(key: Self::Key, conn: &mut Self::Connection) -> Result<Option<Self>, OrmError>;
+
+    fn find_all(conn: &mut Self::Connection) -> Result<Vec<Self>, OrmError>;
+
+    /// Inserts `data` and hands it back.
+    fn insert<'a>(data: Self::New<'a>, conn: &mut Self::Connection) -> Result<Self::New<'a>, OrmError>;
+}
+
+/// Most keys bound in one `IN (...)` by the generated `find_many`, `find_map`
+/// and `find_many_with_x`, longer lists run a query per chunk. Below the 999
+/// parameters of older SQLite builds with room for the other filters, MySQL
+/// and PostgreSQL allow 65535.
+pub const FIND_MANY_CHUNK_SIZE: usize = 900;
+
+/// A `one_to_many` or `many_to_many` relation to `R`.
+///
+/// Only implemented for the first relation to a given type without a
+/// `where` condition, an entity with two relations to the same `R` still
+/// has both `get_x` methods.
+pub trait HasMany<R: Entity>: Entity {
+    fn load_children(&self, conn: &mut Self::Connection) -> Result<Vec<R>, OrmError>;
+}
+
+/// A `many_to_one` relation to `R`, `None` when the foreign key is null.
+///
+/// Only implemented for the first relation to a given type.
+pub trait BelongsTo<R: Entity>: Entity {
+    fn load_parent(&self, conn: &mut Self::Connection) -> Result<Option<R>, OrmError>;
+}
@@ -0,0 +1,48 @@
+use crate::FieldError;
+use std::fmt;
+
+/// Error returned by the trait methods of the generated entities.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OrmError {
+    /// The query failed in diesel or in the database.
+    Diesel(diesel::result::Error),
+    /// `update` of an entity with `#[orm(version = ...)]` found no row with
+    /// the loaded version, the row was changed or deleted since it was loaded.
+    StaleObject { entity: &'static str },
+    /// A `Hooks` method refused the operation.
+    Hook(Box<dyn std::error::Error + Send + Sync>),
+    /// Fields rejected by their `#[validate(...)]` rules.
+    Validation(Vec<FieldError>),
+}
+
+impl fmt::Display for OrmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrmError::Diesel(err) => write!(f, "{}", err),
+            OrmError::StaleObject { entity } => write!(f, "`{}` was changed since it was loaded", entity),
+            OrmError::Hook(err) => write!(f, "{}", err),
+            OrmError::Validation(errors) => {
+                let errors: Vec<String> = errors.iter().map(FieldError::to_string).collect();
+                write!(f, "invalid data: {}", errors.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrmError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OrmError::Diesel(err) => Some(err),
+            OrmError::StaleObject { .. } => None,
+            OrmError::Hook(err) => Some(err.as_ref()),
+            OrmError::Validation(_) => None,
+        }
+    }
+}
+
+impl From<diesel::result::Error> for OrmError {
+    fn from(err: diesel::result::Error) -> Self {
+        OrmError::Diesel(err)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{Entity, OrmError};
+
+/// Callbacks around the generated CRUD methods of an entity with
+/// `#[orm(hooks)]`, each one does nothing unless overridden.
+///
+/// An error returned by a `before_` hook stops the operation, an error of
+/// an `after_` hook is returned once the row is written.
+pub trait Hooks: Entity {
+    fn before_insert(_data: &mut Self::New<'_>) -> Result<(), OrmError> {
+        Ok(())
+    }
+
+    /// Called with the inserted row as read back from the database.
+    fn after_insert(&self) -> Result<(), OrmError> {
+        Ok(())
+    }
+
+    fn before_update(&mut self) -> Result<(), OrmError> {
+        Ok(())
+    }
+
+    /// Called before `delete` and `soft_delete`, an error keeps the row.
+    fn before_delete(&self) -> Result<(), OrmError> {
+        Ok(())
+    }
+
+    /// Called once the row is deleted or soft deleted, not for a row that
+    /// was already gone.
+    fn after_delete(&self) -> Result<(), OrmError> {
+        Ok(())
+    }
+}
@@ -1 +0,0 @@
-syn::custom_keyword!(by);
\ No newline at end of file
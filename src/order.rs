@@ -0,0 +1,111 @@
+use crate::Entity;
+use diesel::expression::expression_types::NotSelectable;
+use diesel::expression::is_aggregate::Never;
+use diesel::expression::ValidGrouping;
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::query_source::{AppearsInFromClause, Column, Once};
+use diesel::{AppearsOnTable, Connection, Expression, QueryResult, Table};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+type Backend<E> = <<E as Entity>::Connection as Connection>::Backend;
+
+/// A column of the `ORDER BY` and whether it is descending.
+type Term<E> = (Box<dyn QueryFragment<Backend<E>> + Send>, bool);
+
+/// Columns of the table of `E` to order the rows of `find_all`,
+/// `find_all_eager` and `find_all_with_x` by, the primary key is always
+/// added last so rows of the same entity stay together in a join.
+///
+/// ```ignore
+/// let users = User::find_all(Some(Order::desc(users::name).then_asc(users::id)), conn);
+/// ```
+pub struct Order<E: Entity> {
+    terms: Vec<Term<E>>,
+    entity: PhantomData<fn() -> E>,
+}
+
+impl<E: Entity> Order<E> {
+    pub fn asc<C>(column: C) -> Self
+    where
+        C: Column<Table = E::Table> + QueryFragment<Backend<E>> + Send + 'static,
+    {
+        Self { terms: Vec::new(), entity: PhantomData }.then_asc(column)
+    }
+
+    pub fn desc<C>(column: C) -> Self
+    where
+        C: Column<Table = E::Table> + QueryFragment<Backend<E>> + Send + 'static,
+    {
+        Self { terms: Vec::new(), entity: PhantomData }.then_desc(column)
+    }
+
+    pub fn then_asc<C>(mut self, column: C) -> Self
+    where
+        C: Column<Table = E::Table> + QueryFragment<Backend<E>> + Send + 'static,
+    {
+        self.terms.push((Box::new(column), false));
+        self
+    }
+
+    pub fn then_desc<C>(mut self, column: C) -> Self
+    where
+        C: Column<Table = E::Table> + QueryFragment<Backend<E>> + Send + 'static,
+    {
+        self.terms.push((Box::new(column), true));
+        self
+    }
+
+    /// `order` followed by the primary key, the `ORDER BY` of the
+    /// generated loaders.
+    #[doc(hidden)]
+    pub fn with_primary_key(order: Option<Self>) -> Self
+    where
+        <E::Table as Table>::PrimaryKey: QueryFragment<Backend<E>> + Send + 'static,
+    {
+        let mut order = order.unwrap_or(Self { terms: Vec::new(), entity: PhantomData });
+        order.terms.push((Box::new(E::get_table_ref().primary_key()), false));
+        order
+    }
+}
+
+impl<E: Entity> Expression for Order<E> {
+    type SqlType = NotSelectable;
+}
+
+impl<E: Entity, QS> AppearsOnTable<QS> for Order<E> where QS: AppearsInFromClause<E::Table, Count = Once> {}
+
+impl<E: Entity, GB> ValidGrouping<GB> for Order<E> {
+    type IsAggregate = Never;
+}
+
+impl<E: Entity> QueryId for Order<E> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<E: Entity> QueryFragment<Backend<E>> for Order<E> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Backend<E>>) -> QueryResult<()> {
+        for (position, (term, descending)) in self.terms.iter().enumerate() {
+            if position > 0 {
+                pass.push_sql(", ");
+            }
+            term.walk_ast(pass.reborrow())?;
+            if *descending {
+                pass.push_sql(" DESC");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sorts the children of a `one_to_many` relation declared with
+/// `order_by = field [asc | desc]`, keeping the query order of equal keys.
+#[doc(hidden)]
+pub fn sort_children<T, K: PartialOrd>(children: &mut [T], key: impl Fn(&T) -> &K, descending: bool) {
+    children.sort_by(|left, right| {
+        let ordering = key(left).partial_cmp(key(right)).unwrap_or(Ordering::Equal);
+        if descending { ordering.reverse() } else { ordering }
+    });
+}
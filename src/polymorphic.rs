@@ -0,0 +1,12 @@
+use diesel::sql_types::Bool;
+use diesel::Expression;
+
+/// An entity with a `#[polymorphic(name, types(...))]` association able to
+/// point at `Target`, implemented by the derive for every listed type so
+/// `#[one_to_many(x = Self, polymorphic)]` on `Target` keeps only its rows.
+pub trait Polymorphic<Target> {
+    /// `name_type = "Target"`.
+    type OfType: Expression<SqlType = Bool>;
+
+    fn of_type() -> Self::OfType;
+}
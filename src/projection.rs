@@ -0,0 +1,28 @@
+use diesel::expression::BoxableExpression;
+use diesel::sql_types::{BigInt, Bool, Nullable};
+
+use crate::{Entity, OrmError};
+
+type Backend<E> = <<E as Entity>::Connection as diesel::Connection>::Backend;
+
+/// Condition on the table of `E` restricting the rows a projection loads.
+pub type ProjectionFilter<E> = Box<dyn BoxableExpression<<E as Entity>::Table, Backend<E>, SqlType = Nullable<Bool>>>;
+
+/// Key column of the table of `E` loaded next to each projected row, the
+/// primary key or a foreign key the rows are matched on.
+pub type ProjectionKey<E> = Box<dyn BoxableExpression<<E as Entity>::Table, Backend<E>, SqlType = Nullable<BigInt>>>;
+
+/// A struct selecting some columns of `E`, generated for every
+/// `#[orm(projection(...))]` and implemented by `E` itself for all columns.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a projection of `{E}`",
+    label = "declare it with `#[orm(projection({Self}: ...))]` on `{E}`"
+)]
+pub trait Projection<E: Entity>: Sized {
+    /// The rows matching `filter`, all rows without one, each with its `key`.
+    fn load_keyed(
+        filter: Option<ProjectionFilter<E>>,
+        key: ProjectionKey<E>,
+        conn: &mut E::Connection,
+    ) -> Result<Vec<(Option<i64>, Self)>, OrmError>;
+}
@@ -0,0 +1,48 @@
+use crate::{Entity, OrmError};
+use diesel::JoinTo;
+
+/// A relation of `E`, implemented by the marker types behind the generated
+/// `X::FIELD` constants.
+pub trait Relation<E: Entity> {
+    /// Loads the relation for every entity in `lazy` and stores it in the
+    /// matching field, which is left untouched for entities without rows.
+    fn load_into(&self, lazy: &mut [E::Lazy], conn: &mut E::Connection) -> Result<(), OrmError>;
+}
+
+/// One or more relations of `E` loaded together, a single relation
+/// marker or a tuple of them.
+pub trait Relations<E: Entity> {
+    fn load_into(&self, lazy: &mut [E::Lazy], conn: &mut E::Connection) -> Result<(), OrmError>;
+}
+
+macro_rules! impl_relations_for_tuple {
+    ($($rel:ident),+) => {
+        impl<E: Entity, $($rel: Relation<E>),+> Relations<E> for ($($rel,)+) {
+            #[allow(non_snake_case)]
+            fn load_into(&self, lazy: &mut [E::Lazy], conn: &mut E::Connection) -> Result<(), OrmError> {
+                let ($($rel,)+) = self;
+                $($rel.load_into(lazy, conn)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_relations_for_tuple!(A);
+impl_relations_for_tuple!(A, B);
+impl_relations_for_tuple!(A, B, C);
+impl_relations_for_tuple!(A, B, C, D);
+impl_relations_for_tuple!(A, B, C, D, F);
+impl_relations_for_tuple!(A, B, C, D, F, G);
+impl_relations_for_tuple!(A, B, C, D, F, G, H);
+impl_relations_for_tuple!(A, B, C, D, F, G, H, I);
+
+/// The `ON` clause diesel derives from `joinable!` for joining `right` to
+/// `left`, so generated joins can extend it instead of spelling it out.
+#[doc(hidden)]
+pub fn join_on<Left, Right>(_left: Left, right: Right) -> <Left as JoinTo<Right>>::OnClause
+where
+    Left: JoinTo<Right>,
+{
+    <Left as JoinTo<Right>>::join_target(right).1
+}
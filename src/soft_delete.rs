@@ -0,0 +1,14 @@
+use diesel::sql_types::Bool;
+use diesel::Expression;
+
+/// The rows of an entity queries are allowed to see, implemented by the
+/// derive for every entity so a join can filter a related table without
+/// knowing whether it is soft deleted.
+///
+/// With `#[orm(soft_delete = column)]` the rows where `column` is set are
+/// excluded, without it the filter keeps every row.
+pub trait SoftDelete {
+    type NotDeleted: Expression<SqlType = Bool>;
+
+    fn not_deleted() -> Self::NotDeleted;
+}
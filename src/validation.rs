@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// A field rejected by one of its `#[validate(...)]` rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` {}", self.field, self.message)
+    }
+}
+
+/// `length(min = .., max = ..)`, counted in characters.
+#[doc(hidden)]
+pub fn check_length(value: &str, min: Option<usize>, max: Option<usize>) -> Option<String> {
+    let length = value.chars().count();
+    match (min, max) {
+        (Some(min), _) if length < min => Some(format!("must be at least {} characters", min)),
+        (_, Some(max)) if length > max => Some(format!("must be at most {} characters", max)),
+        _ => None,
+    }
+}
+
+/// `email`, a non empty local part and a dotted domain around a single `@`.
+#[doc(hidden)]
+pub fn check_email(value: &str) -> Option<String> {
+    let valid = match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|part| !part.is_empty())
+        }
+        None => false,
+    };
+    if valid {
+        None
+    } else {
+        Some("must be a valid email address".to_string())
+    }
+}
+
+/// `range(min = .., max = ..)`, both bounds included.
+#[doc(hidden)]
+pub fn check_range<T: PartialOrd + fmt::Display>(value: &T, min: Option<T>, max: Option<T>) -> Option<String> {
+    match (min, max) {
+        (Some(min), _) if *value < min => Some(format!("must be at least {}", min)),
+        (_, Some(max)) if *value > max => Some(format!("must be at most {}", max)),
+        _ => None,
+    }
+}
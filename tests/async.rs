@@ -0,0 +1,60 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::*;
+use diesel::SqliteConnection;
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+
+fn seeded_async_connection() -> SyncConnectionWrapper<SqliteConnection> {
+    SyncConnectionWrapper::new(seeded_connection())
+}
+
+#[tokio::test]
+async fn find_and_insert_async() {
+    let mut conn = seeded_async_connection();
+    assert_eq!(User::find_async(1, &mut conn).await.unwrap(), Some(user(1, "ann")));
+    User::insert_async(NewUser { name: "cid".into() }, &mut conn).await.unwrap();
+    assert_eq!(User::find_async(3, &mut conn).await.unwrap(), Some(user(3, "cid")));
+    assert_eq!(User::find_all_async(None, &mut conn).await.len(), 3);
+}
+
+#[tokio::test]
+async fn getters_async() {
+    let mut conn = seeded_async_connection();
+    let ann = user(1, "ann");
+    let mut posts = ann.get_posts_async(&mut conn).await;
+    posts.sort_by_key(|post| post.id);
+    assert_eq!(posts, vec![first_post(), draft_post()]);
+    assert_eq!(ann.get_roles_async(&mut conn).await, vec![admin()]);
+    assert_eq!(draft_post().get_user_async(&mut conn).await, ann);
+    assert_eq!(draft_post().get_category_async(&mut conn).await, None);
+}
+
+#[tokio::test]
+async fn find_all_with_async() {
+    let mut conn = seeded_async_connection();
+    let users = User::find_all_with_posts_async(None, &mut conn).await.unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].posts.as_ref().map(Vec::len), Some(2));
+    assert_eq!(users[1].posts, None);
+}
+
+#[tokio::test]
+async fn soft_delete_async() {
+    let mut conn = seeded_async_connection();
+    draft_post().soft_delete_async(&mut conn).await.unwrap();
+    assert_eq!(Post::find_async(2, &mut conn).await.unwrap(), None);
+    assert_eq!(Post::find_all_with_deleted_async(None, &mut conn).await.len(), 2);
+    draft_post().restore_async(&mut conn).await.unwrap();
+    assert_eq!(Post::find_all_async(None, &mut conn).await, vec![first_post(), draft_post()]);
+}
+
+#[tokio::test]
+async fn update_async() {
+    let mut conn = seeded_async_connection();
+    let mut post = draft_post();
+    post.title = "final".into();
+    post.update_async(&mut conn).await.unwrap();
+    assert_eq!(Post::find_async(2, &mut conn).await.unwrap(), Some(post));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::*;
+
+/// `seeded_connection` with `count` more users, each with one post.
+fn connection_with_users(count: i64) -> diesel::SqliteConnection {
+    let mut conn = seeded_connection();
+    for user_id in 3..(3 + count) {
+        User::insert(NewUser { name: format!("user{}", user_id) }, &mut conn).unwrap();
+        Post::insert(NewPost { user_id, category_id: None, title: "hello".into(), published: None }, &mut conn).unwrap();
+    }
+    conn
+}
+
+#[test]
+fn iter_all_pages_through_the_table() {
+    let mut conn = connection_with_users(5);
+    let users: Vec<User> = User::iter_all(2, &mut conn).collect::<Result<_, _>>().unwrap();
+    assert_eq!(users.len(), 7);
+    assert!(users.windows(2).all(|pair| pair[0].id < pair[1].id));
+    assert_eq!(User::iter_all(7, &mut conn).count(), 7);
+    assert_eq!(User::iter_all(0, &mut conn).count(), 0);
+
+    draft_post().soft_delete(&mut conn).unwrap();
+    assert_eq!(Post::iter_all(3, &mut conn).count(), 6);
+}
+
+#[test]
+fn pages_never_split_a_parent() {
+    let mut conn = connection_with_users(3);
+    let users: Vec<UserLazy> = User::iter_all_with_posts(1, &mut conn).collect::<Result<_, _>>().unwrap();
+    assert_eq!(users.len(), 5);
+    assert_eq!(users[0].posts.as_ref().map(Vec::len), Some(2));
+    assert_eq!(users[1].posts, None);
+    assert_eq!(users[2].posts.as_ref().map(Vec::len), Some(1));
+
+    let users: Vec<UserWithAll> = User::iter_all_eager(2, &mut conn).collect::<Result<_, _>>().unwrap();
+    assert_eq!(users.len(), 5);
+    assert_eq!(users[0].posts.len(), 2);
+    assert_eq!(users[0].published_posts, vec![first_post()]);
+    assert_eq!(users[4].self_data, user(5, "user5"));
+}
+
+#[test]
+fn pages_go_past_parents_left_out_by_the_join() {
+    let mut conn = empty_connection();
+    for (author_id, name) in [(1, "ann"), (2, "bob"), (3, "cid")] {
+        Author::insert(NewAuthor { name: name.into() }, &mut conn).unwrap();
+        Book::insert(NewBook { author_id, title: format!("by {}", name) }, &mut conn).unwrap();
+    }
+    Author::find(2, &mut conn).unwrap().unwrap().soft_delete(&mut conn).unwrap();
+    assert_eq!(Book::find_all_with_author(None, &mut conn).unwrap().len(), 2);
+
+    let books: Vec<BookLazy> = Book::iter_all_with_author(1, &mut conn).collect::<Result<_, _>>().unwrap();
+    assert_eq!(books.iter().map(|book| book.self_data.id).collect::<Vec<_>>(), vec![1, 3]);
+    let books: Vec<BookWithAll> = Book::iter_all_eager(1, &mut conn).collect::<Result<_, _>>().unwrap();
+    assert_eq!(books.iter().map(|book| book.self_data.id).collect::<Vec<_>>(), vec![1, 3]);
+}
@@ -0,0 +1,388 @@
+#![allow(dead_code)]
+
+use abstract_orm::{Hooks, Joinable, OrmError};
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+pub mod schema {
+    diesel::table! {
+        users (id) {
+            id -> BigInt,
+            name -> Text,
+        }
+    }
+    diesel::table! {
+        categories (id) {
+            id -> BigInt,
+            name -> Text,
+            deleted_at -> Nullable<Timestamp>,
+        }
+    }
+    diesel::table! {
+        posts (id) {
+            id -> BigInt,
+            user_id -> BigInt,
+            category_id -> Nullable<BigInt>,
+            title -> Text,
+            published -> Bool,
+            deleted_at -> Nullable<Timestamp>,
+        }
+    }
+    diesel::table! {
+        roles (id) {
+            id -> BigInt,
+            name -> Text,
+            deleted_at -> Nullable<Timestamp>,
+        }
+    }
+    diesel::table! {
+        user_roles (user_id, role_id) {
+            user_id -> BigInt,
+            role_id -> BigInt,
+            granted_by -> Text,
+        }
+    }
+    diesel::table! {
+        tags (id) {
+            id -> BigInt,
+            name -> Text,
+            created_at -> Timestamp,
+            updated_at -> Timestamp,
+            lock_version -> Integer,
+            deleted_at -> Nullable<Timestamp>,
+        }
+    }
+    diesel::table! {
+        accounts (id) {
+            id -> BigInt,
+            email -> Text,
+            name -> Text,
+            age -> Nullable<Integer>,
+        }
+    }
+    diesel::table! {
+        photos (id) {
+            id -> BigInt,
+            url -> Text,
+        }
+    }
+    diesel::table! {
+        comments (id) {
+            id -> BigInt,
+            body -> Text,
+            commentable_type -> Text,
+            commentable_id -> BigInt,
+        }
+    }
+    diesel::table! {
+        authors (id) {
+            id -> BigInt,
+            name -> Text,
+            deleted_at -> Nullable<Timestamp>,
+        }
+    }
+    diesel::table! {
+        books (id) {
+            id -> BigInt,
+            author_id -> BigInt,
+            title -> Text,
+        }
+    }
+    diesel::table! {
+        countries (code) {
+            code -> Text,
+            name -> Text,
+        }
+    }
+    diesel::joinable!(posts -> users (user_id));
+    diesel::joinable!(posts -> categories (category_id));
+    diesel::joinable!(user_roles -> users (user_id));
+    diesel::joinable!(user_roles -> roles (role_id));
+    diesel::joinable!(comments -> posts (commentable_id));
+    diesel::joinable!(comments -> photos (commentable_id));
+    diesel::joinable!(books -> authors (author_id));
+    diesel::allow_tables_to_appear_in_same_query!(users, categories, posts, roles, user_roles, photos, comments);
+    diesel::allow_tables_to_appear_in_same_query!(authors, books);
+}
+
+use schema::{accounts, authors, books, categories, comments, countries, photos, posts, roles, tags, user_roles, users};
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = users)]
+#[orm(backend = sqlite, projection(UserName: name))]
+#[one_to_many(posts = Post, published_posts = Post, where = posts::published.eq(true))]
+#[many_to_many(roles = Role, admin_roles = Role, where = roles::name.eq("admin"), role_links = Role, with_link)]
+#[has_many_through(comments_on_my_posts = Comment through posts, where = <Comment as abstract_orm::Polymorphic<Post>>::of_type())]
+pub struct User {
+    pub id: i64,
+    #[orm(unique)]
+    pub name: String,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = categories)]
+#[orm(backend = sqlite, soft_delete = deleted_at)]
+#[one_to_many(posts = Post, order_by = title)]
+pub struct Category {
+    pub id: i64,
+    pub name: String,
+    #[orm(skip_insert)]
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = posts)]
+#[diesel(belongs_to(User))]
+#[diesel(belongs_to(Category))]
+#[orm(backend = sqlite, soft_delete = deleted_at, projection(PostTitle: id, title))]
+#[one_to_many(comments = Comment, polymorphic)]
+pub struct Post {
+    pub id: i64,
+    #[many_to_one(User)]
+    pub user_id: i64,
+    #[many_to_one(Option<Category>)]
+    #[orm(index)]
+    pub category_id: Option<i64>,
+    pub title: String,
+    #[orm(default, index)]
+    pub published: bool,
+    #[orm(skip_insert)]
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = roles)]
+#[orm(backend = sqlite, soft_delete = deleted_at)]
+pub struct Role {
+    pub id: i64,
+    pub name: String,
+    #[orm(skip_insert)]
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = user_roles)]
+#[diesel(primary_key(user_id, role_id))]
+#[diesel(belongs_to(User))]
+#[diesel(belongs_to(Role))]
+#[orm(backend = sqlite)]
+pub struct UserToRole {
+    pub user_id: i64,
+    pub role_id: i64,
+    pub granted_by: String,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = photos)]
+#[orm(backend = sqlite)]
+#[one_to_many(comments = Comment, polymorphic)]
+pub struct Photo {
+    pub id: i64,
+    pub url: String,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = comments)]
+#[diesel(belongs_to(Post, foreign_key = commentable_id))]
+#[diesel(belongs_to(Photo, foreign_key = commentable_id))]
+#[orm(backend = sqlite)]
+#[polymorphic(commentable, types(Post, Photo))]
+pub struct Comment {
+    pub id: i64,
+    pub body: String,
+    pub commentable_type: String,
+    pub commentable_id: i64,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = tags)]
+#[orm(backend = sqlite, timestamps, version = lock_version, soft_delete = deleted_at, hooks)]
+pub struct Tag {
+    pub id: i64,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub lock_version: i32,
+    #[orm(skip_insert)]
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = accounts)]
+#[orm(backend = sqlite)]
+pub struct Account {
+    pub id: i64,
+    #[validate(email, length(max = 64))]
+    pub email: String,
+    #[validate(length(min = 2, max = 32))]
+    pub name: String,
+    #[validate(range(min = 0, max = 150))]
+    pub age: Option<i32>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = authors)]
+#[orm(backend = sqlite, soft_delete = deleted_at)]
+pub struct Author {
+    pub id: i64,
+    pub name: String,
+    #[orm(skip_insert)]
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = books)]
+#[diesel(belongs_to(Author))]
+#[orm(backend = sqlite)]
+pub struct Book {
+    pub id: i64,
+    #[many_to_one(Author)]
+    pub author_id: i64,
+    pub title: String,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Debug, PartialEq, Joinable)]
+#[diesel(table_name = countries)]
+#[diesel(primary_key(code))]
+#[orm(backend = sqlite)]
+pub struct Country {
+    pub code: String,
+    pub name: String,
+}
+
+thread_local! {
+    static TAG_EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The `Tag` hooks called on this thread since the last call.
+pub fn take_tag_events() -> Vec<String> {
+    TAG_EVENTS.with(|events| events.take())
+}
+
+fn record_tag_event(event: String) {
+    TAG_EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+impl Hooks for Tag {
+    fn before_insert(data: &mut NewTag) -> Result<(), OrmError> {
+        data.name = data.name.trim().to_string();
+        if data.name.is_empty() {
+            return Err(OrmError::Hook("a tag needs a name".into()));
+        }
+        Ok(())
+    }
+
+    fn after_insert(&self) -> Result<(), OrmError> {
+        record_tag_event(format!("inserted {} {}", self.id, self.name));
+        Ok(())
+    }
+
+    fn before_update(&mut self) -> Result<(), OrmError> {
+        self.name = self.name.trim().to_string();
+        record_tag_event(format!("updating {}", self.id));
+        Ok(())
+    }
+
+    fn before_delete(&self) -> Result<(), OrmError> {
+        if self.name == "pinned" {
+            return Err(OrmError::Hook("a pinned tag stays".into()));
+        }
+        Ok(())
+    }
+
+    fn after_delete(&self) -> Result<(), OrmError> {
+        record_tag_event(format!("deleted {}", self.id));
+        Ok(())
+    }
+}
+
+pub const CREATE_TABLES: &str = "
+    CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL);
+    CREATE TABLE categories (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, deleted_at TIMESTAMP);
+    CREATE TABLE posts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        user_id BIGINT NOT NULL REFERENCES users (id),
+        category_id BIGINT REFERENCES categories (id),
+        title TEXT NOT NULL,
+        published BOOLEAN NOT NULL DEFAULT 0,
+        deleted_at TIMESTAMP
+    );
+    CREATE TABLE roles (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, deleted_at TIMESTAMP);
+    CREATE TABLE user_roles (
+        user_id BIGINT NOT NULL REFERENCES users (id),
+        role_id BIGINT NOT NULL REFERENCES roles (id),
+        granted_by TEXT NOT NULL,
+        PRIMARY KEY (user_id, role_id)
+    );
+    CREATE TABLE tags (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL,
+        created_at TIMESTAMP NOT NULL,
+        updated_at TIMESTAMP NOT NULL,
+        lock_version INTEGER NOT NULL,
+        deleted_at TIMESTAMP
+    );
+    CREATE TABLE photos (id INTEGER PRIMARY KEY AUTOINCREMENT, url TEXT NOT NULL);
+    CREATE TABLE comments (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        body TEXT NOT NULL,
+        commentable_type TEXT NOT NULL,
+        commentable_id BIGINT NOT NULL
+    );
+    CREATE TABLE accounts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        email TEXT NOT NULL,
+        name TEXT NOT NULL,
+        age INTEGER
+    );
+    CREATE TABLE countries (code TEXT PRIMARY KEY NOT NULL, name TEXT NOT NULL);
+    CREATE TABLE authors (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, deleted_at TIMESTAMP);
+    CREATE TABLE books (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        author_id BIGINT NOT NULL REFERENCES authors (id),
+        title TEXT NOT NULL
+    );
+";
+
+/// An in-memory database with the tables above and no rows.
+pub fn empty_connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").expect("can not open in-memory sqlite");
+    conn.batch_execute(CREATE_TABLES).expect("can not create tables");
+    conn
+}
+
+/// An in-memory database where `ann` has two posts and the `admin` role,
+/// and `bob` has nothing.
+pub fn seeded_connection() -> SqliteConnection {
+    let mut conn = empty_connection();
+    User::insert(NewUser { name: "ann".into() }, &mut conn).unwrap();
+    User::insert(NewUser { name: "bob".into() }, &mut conn).unwrap();
+    Category::insert(NewCategory { name: "news".into() }, &mut conn).unwrap();
+    Post::insert(NewPost { user_id: 1, category_id: Some(1), title: "first".into(), published: Some(true) }, &mut conn).unwrap();
+    Post::insert(NewPost { user_id: 1, category_id: None, title: "draft".into(), published: None }, &mut conn).unwrap();
+    Role::insert(NewRole { name: "admin".into() }, &mut conn).unwrap();
+    UserToRole::insert(NewUserToRole { user_id: 1, role_id: 1, granted_by: "setup".into() }, &mut conn).unwrap();
+    conn
+}
+
+pub fn user(id: i64, name: &str) -> User {
+    User { id, name: name.into() }
+}
+
+pub fn admin() -> Role {
+    Role { id: 1, name: "admin".into(), deleted_at: None }
+}
+
+pub fn news() -> Category {
+    Category { id: 1, name: "news".into(), deleted_at: None }
+}
+
+pub fn first_post() -> Post {
+    Post { id: 1, user_id: 1, category_id: Some(1), title: "first".into(), published: true, deleted_at: None }
+}
+
+pub fn draft_post() -> Post {
+    Post { id: 2, user_id: 1, category_id: None, title: "draft".into(), published: false, deleted_at: None }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn insert_returns_the_inserted_data() {
+    let mut conn = empty_connection();
+    let inserted = User::insert(NewUser { name: "ann".into() }, &mut conn).unwrap();
+    assert_eq!(inserted.name, "ann");
+}
+
+#[test]
+fn find_by_primary_key() {
+    let mut conn = seeded_connection();
+    assert_eq!(User::find(2, &mut conn).unwrap(), Some(user(2, "bob")));
+    assert_eq!(User::find(42, &mut conn).unwrap(), None);
+}
+
+#[test]
+fn find_all_rows() {
+    let mut conn = seeded_connection();
+    assert_eq!(User::find_all(None, &mut conn), vec![user(1, "ann"), user(2, "bob")]);
+}
+
+#[test]
+fn insert_with_default_column() {
+    let mut conn = seeded_connection();
+    assert_eq!(Post::find(1, &mut conn).unwrap(), Some(first_post()));
+    assert_eq!(Post::find(2, &mut conn).unwrap(), Some(draft_post()));
+}
+
+#[test]
+fn insert_into_join_table_keeps_composite_key() {
+    let mut conn = seeded_connection();
+    User::insert(NewUser { name: "cid".into() }, &mut conn).unwrap();
+    let link = UserToRole::insert(NewUserToRole { user_id: 3, role_id: 1, granted_by: "setup".into() }, &mut conn).unwrap();
+    assert_eq!((link.user_id, link.role_id), (3, 1));
+    assert_eq!(UserToRole::find_all(None, &mut conn).len(), 2);
+}
+
+#[test]
+fn update_writes_the_changed_columns() {
+    let mut conn = seeded_connection();
+    let mut post = draft_post();
+    post.title = "final".into();
+    post.category_id = Some(1);
+    post.update(&mut conn).unwrap();
+    assert_eq!(Post::find(2, &mut conn).unwrap(), Some(post));
+    assert_eq!(Post::find(1, &mut conn).unwrap(), Some(first_post()));
+}
+
+#[test]
+fn delete_removes_the_row() {
+    let mut conn = seeded_connection();
+    draft_post().delete(&mut conn).unwrap();
+    assert_eq!(Post::find_all_with_deleted(None, &mut conn), vec![first_post()]);
+    let link = UserToRole { user_id: 1, role_id: 1, granted_by: "setup".into() };
+    link.delete(&mut conn).unwrap();
+    assert!(UserToRole::find_all(None, &mut conn).is_empty());
+}
+
+#[test]
+fn find_by_a_text_primary_key() {
+    use diesel::connection::SimpleConnection;
+
+    let mut conn = empty_connection();
+    conn.batch_execute("INSERT INTO countries (code, name) VALUES ('fr', 'France'), ('it', 'Italy')").unwrap();
+    let italy = Country { code: "it".into(), name: "Italy".into() };
+    assert_eq!(Country::find("it".into(), &mut conn).unwrap(), Some(italy));
+    assert_eq!(Country::find_all(None, &mut conn).len(), 2);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use abstract_orm::{BelongsTo, Entity, HasMany, OrmError};
+use common::*;
+
+fn find_or_fail<T: Entity>(key: T::Key, conn: &mut T::Connection) -> Result<T, &'static str> {
+    T::find(key, conn).map_err(|_| "query failed")?.ok_or("missing")
+}
+
+fn count_children<P: HasMany<C>, C: Entity>(parent: &P, conn: &mut P::Connection) -> Result<usize, OrmError> {
+    Ok(parent.load_children(conn)?.len())
+}
+
+#[test]
+fn find_through_entity() {
+    let mut conn = seeded_connection();
+    assert_eq!(find_or_fail::<User>(1, &mut conn), Ok(user(1, "ann")));
+    assert_eq!(find_or_fail::<User>(42, &mut conn), Err("missing"));
+    assert_eq!(find_or_fail::<UserToRole>((1, 1), &mut conn).map(|link| link.user_id), Ok(1));
+}
+
+#[test]
+fn has_many_through_trait() {
+    let mut conn = seeded_connection();
+    let ann = user(1, "ann");
+    assert_eq!(count_children::<User, Post>(&ann, &mut conn).unwrap(), 2);
+    assert_eq!(HasMany::<Role>::load_children(&ann, &mut conn).unwrap(), vec![admin()]);
+}
+
+#[test]
+fn belongs_to_through_trait() {
+    let mut conn = seeded_connection();
+    assert_eq!(BelongsTo::<User>::load_parent(&first_post(), &mut conn).unwrap(), Some(user(1, "ann")));
+    assert_eq!(BelongsTo::<Category>::load_parent(&first_post(), &mut conn).unwrap(), Some(news()));
+    assert_eq!(BelongsTo::<Category>::load_parent(&draft_post(), &mut conn).unwrap(), None);
+}
+
+fn insert_and_list<T: Entity>(data: T::New<'_>, conn: &mut T::Connection) -> Result<usize, OrmError> {
+    T::insert(data, conn)?;
+    Ok(T::find_all(conn)?.len())
+}
+
+#[test]
+fn insert_and_find_all_through_entity() {
+    let mut conn = seeded_connection();
+    assert_eq!(insert_and_list::<User>(NewUser { name: "cid".into() }, &mut conn).unwrap(), 3);
+    assert_eq!(insert_and_list::<Role>(NewRole { name: "editor".into() }, &mut conn).unwrap(), 2);
+}
+
+#[test]
+fn associated_generated_types() {
+    fn lazy_of<T: Entity>(lazy: T::Lazy) -> T::Lazy {
+        lazy
+    }
+    let lazy = lazy_of::<User>(UserLazy::init(user(1, "ann")));
+    assert_eq!(lazy.self_data, user(1, "ann"));
+    let role: <Role as Entity>::Lazy = admin();
+    assert_eq!(role, admin());
+}
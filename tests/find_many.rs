@@ -0,0 +1,52 @@
+mod common;
+
+use abstract_orm::FIND_MANY_CHUNK_SIZE;
+use common::*;
+use diesel::connection::{Connection, InstrumentationEvent};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn find_many_skips_missing_keys() {
+    let mut conn = seeded_connection();
+    assert_eq!(User::find_many(&[2, 7, 1], &mut conn).unwrap(), vec![user(1, "ann"), user(2, "bob")]);
+    assert!(User::find_many(&[], &mut conn).unwrap().is_empty());
+
+    draft_post().soft_delete(&mut conn).unwrap();
+    assert_eq!(Post::find_many(&[1, 2], &mut conn).unwrap(), vec![first_post()]);
+}
+
+#[test]
+fn find_map_is_keyed_by_id() {
+    let mut conn = seeded_connection();
+    let users = User::find_map(&[1, 2, 3], &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[&2], user(2, "bob"));
+}
+
+/// The number of bind parameters of each query `conn` runs from now on.
+fn record_binds(conn: &mut diesel::SqliteConnection) -> Arc<Mutex<Vec<usize>>> {
+    let binds = Arc::new(Mutex::new(Vec::new()));
+    let recorded = binds.clone();
+    conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+        if let InstrumentationEvent::StartQuery { query, .. } = event {
+            let sql = query.to_string();
+            let sql = sql.split(" -- binds:").next().unwrap_or_default();
+            recorded.lock().unwrap().push(sql.matches('?').count());
+        }
+    });
+    binds
+}
+
+#[test]
+fn long_key_lists_are_chunked() {
+    let mut conn = seeded_connection();
+    let keys: Vec<i64> = (1..=(FIND_MANY_CHUNK_SIZE as i64 * 2 + 1)).rev().collect();
+    let binds = record_binds(&mut conn);
+    assert_eq!(User::find_many(&keys, &mut conn).unwrap().len(), 2);
+    assert_eq!(*binds.lock().unwrap(), vec![FIND_MANY_CHUNK_SIZE, FIND_MANY_CHUNK_SIZE, 1]);
+    assert_eq!(User::find_map(&keys, &mut conn).unwrap().len(), 2);
+
+    let users = User::find_many_with_posts(&keys, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users.iter().find(|data| data.self_data.id == 1).unwrap().posts.as_ref().map(Vec::len), Some(2));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn get_one_to_many() {
+    let mut conn = seeded_connection();
+    let ann = User::find(1, &mut conn).unwrap().unwrap();
+    let bob = User::find(2, &mut conn).unwrap().unwrap();
+    assert_eq!(ann.get_posts(&mut conn), vec![first_post(), draft_post()]);
+    assert_eq!(bob.get_posts(&mut conn), vec![]);
+}
+
+#[test]
+fn get_many_to_many() {
+    let mut conn = seeded_connection();
+    let ann = User::find(1, &mut conn).unwrap().unwrap();
+    let bob = User::find(2, &mut conn).unwrap().unwrap();
+    assert_eq!(ann.get_roles(&mut conn), vec![admin()]);
+    assert_eq!(bob.get_roles(&mut conn), vec![]);
+}
+
+#[test]
+fn get_many_to_one() {
+    let mut conn = seeded_connection();
+    assert_eq!(first_post().get_user(&mut conn), user(1, "ann"));
+}
+
+#[test]
+fn get_optional_many_to_one() {
+    let mut conn = seeded_connection();
+    assert_eq!(first_post().get_category(&mut conn), Some(news()));
+    assert_eq!(draft_post().get_category(&mut conn), None);
+}
+
+#[test]
+fn lazy_block_keeps_statements() {
+    let mut conn = seeded_connection();
+    abstract_orm::lazy_block! {
+        let ann: User = User::find(1, &mut conn).unwrap().unwrap();
+        let posts = ann.get_posts(&mut conn);
+        assert_eq!(posts.len(), 2);
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use abstract_orm::OrmError;
+use common::*;
+
+#[test]
+fn insert_runs_the_insert_hooks() {
+    let mut conn = empty_connection();
+    let inserted = Tag::insert(NewTag { name: " rust ".into() }, &mut conn).unwrap();
+    assert_eq!(inserted.name, "rust");
+    assert_eq!(take_tag_events(), vec!["inserted 1 rust"]);
+}
+
+#[test]
+fn before_insert_error_stops_the_insert() {
+    let mut conn = empty_connection();
+    let Err(err) = Tag::insert(NewTag { name: "  ".into() }, &mut conn) else {
+        panic!("a blank tag was inserted");
+    };
+    assert!(matches!(err, OrmError::Hook(_)));
+    assert_eq!(err.to_string(), "a tag needs a name");
+    assert!(Tag::find_all(None, &mut conn).is_empty());
+    assert!(take_tag_events().is_empty());
+}
+
+#[test]
+fn update_and_delete_run_their_hooks() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    let mut tag = Tag::find(1, &mut conn).unwrap().unwrap();
+    tag.name = "diesel ".into();
+    tag.update(&mut conn).unwrap();
+    assert_eq!(Tag::find(1, &mut conn).unwrap().unwrap().name, "diesel");
+
+    tag.delete(&mut conn).unwrap();
+    tag.delete(&mut conn).unwrap();
+    assert_eq!(Tag::find(1, &mut conn).unwrap(), None);
+    assert_eq!(take_tag_events(), vec!["inserted 1 rust", "updating 1", "deleted 1"]);
+}
+
+#[test]
+fn soft_delete_runs_the_delete_hooks() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    Tag::insert(NewTag { name: "pinned".into() }, &mut conn).unwrap();
+    let tags = Tag::find_all(None, &mut conn);
+    take_tag_events();
+
+    tags[0].soft_delete(&mut conn).unwrap();
+    tags[0].soft_delete(&mut conn).unwrap();
+    let err = tags[1].soft_delete(&mut conn).unwrap_err();
+    assert_eq!(err.to_string(), "a pinned tag stays");
+    assert!(tags[1].delete(&mut conn).is_err());
+    assert_eq!(Tag::find_all(None, &mut conn), vec![tags[1].clone()]);
+    assert_eq!(take_tag_events(), vec!["deleted 1"]);
+}
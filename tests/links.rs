@@ -0,0 +1,33 @@
+mod common;
+
+use common::*;
+
+fn admin_link() -> (UserToRole, Role) {
+    (UserToRole { user_id: 1, role_id: 1, granted_by: "setup".into() }, admin())
+}
+
+#[test]
+fn getter_keeps_the_join_row() {
+    let mut conn = seeded_connection();
+    assert_eq!(user(1, "ann").get_role_links(&mut conn), vec![admin_link()]);
+    assert_eq!(user(2, "bob").get_role_links(&mut conn), vec![]);
+}
+
+#[test]
+fn loaders_keep_the_join_row() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_with_role_links(None, &mut conn).unwrap();
+    assert_eq!(users[0].role_links, Some(vec![admin_link()]));
+    assert_eq!(users[1].role_links, None);
+
+    let users = User::load_with(User::ROLE_LINKS, &mut conn).unwrap();
+    assert_eq!(users[0].role_links, Some(vec![admin_link()]));
+
+    let users = User::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(users[0].role_links, vec![admin_link()]);
+    assert_eq!(users[1].role_links, vec![]);
+
+    let with_links = User::find_all_with_role_links_as::<UserName, Role>(&mut conn).unwrap();
+    assert_eq!(with_links[0].1, vec![admin_link()]);
+    assert!(with_links[1].1.is_empty());
+}
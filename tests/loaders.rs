@@ -0,0 +1,102 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn find_all_eager_one_to_many() {
+    let mut conn = seeded_connection();
+    let mut users = User::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    users[0].posts.sort_by_key(|post| post.id);
+    assert_eq!(users[0].self_data, user(1, "ann"));
+    assert_eq!(users[0].posts, vec![first_post(), draft_post()]);
+    assert!(users[0].roles.iter().all(|role| *role == admin()));
+    assert_eq!(users[1].self_data, user(2, "bob"));
+    assert_eq!(users[1].posts, vec![]);
+    assert_eq!(users[1].roles, vec![]);
+}
+
+#[test]
+fn find_all_eager_many_to_one() {
+    let mut conn = seeded_connection();
+    let posts = Post::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].self_data, first_post());
+    assert_eq!(posts[0].user, user(1, "ann"));
+    assert_eq!(posts[0].category, Some(news()));
+    assert_eq!(posts[1].self_data, draft_post());
+    assert_eq!(posts[1].category, None);
+}
+
+#[test]
+fn find_all_with_one_to_many() {
+    let mut conn = seeded_connection();
+    let mut users = User::find_all_with_posts(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    users[0].posts.as_mut().unwrap().sort_by_key(|post| post.id);
+    assert_eq!(users[0].posts, Some(vec![first_post(), draft_post()]));
+    assert_eq!(users[0].roles, None);
+    assert_eq!(users[1].posts, None);
+}
+
+#[test]
+fn find_all_with_many_to_many() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_with_roles(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].roles, Some(vec![admin()]));
+    assert_eq!(users[0].posts, None);
+    assert_eq!(users[1].roles, None);
+}
+
+#[test]
+fn find_all_with_many_to_one() {
+    let mut conn = seeded_connection();
+    let posts = Post::find_all_with_user(None, &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].user, Some(user(1, "ann")));
+    assert_eq!(posts[1].user, Some(user(1, "ann")));
+}
+
+#[test]
+fn find_all_with_optional_many_to_one() {
+    let mut conn = seeded_connection();
+    let posts = Post::find_all_with_category(None, &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].category, Some(news()));
+    assert_eq!(posts[1].category, None);
+}
+
+#[test]
+fn load_with_several_relations() {
+    let mut conn = seeded_connection();
+    let mut users = User::load_with((User::POSTS, User::ROLES), &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    users[0].posts.as_mut().unwrap().sort_by_key(|post| post.id);
+    assert_eq!(users[0].self_data, user(1, "ann"));
+    assert_eq!(users[0].posts, Some(vec![first_post(), draft_post()]));
+    assert_eq!(users[0].roles, Some(vec![admin()]));
+    assert_eq!(users[1].posts, None);
+    assert_eq!(users[1].roles, None);
+}
+
+#[test]
+fn load_with_many_to_one_relations() {
+    let mut conn = seeded_connection();
+    let posts = Post::load_with((Post::USER, Post::CATEGORY), &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].user, Some(user(1, "ann")));
+    assert_eq!(posts[0].category, Some(news()));
+    assert_eq!(posts[1].user, Some(user(1, "ann")));
+    assert_eq!(posts[1].category, None);
+}
+
+#[test]
+fn with_relations_of_one_entity() {
+    let mut conn = seeded_connection();
+    let ann = user(1, "ann").with_relations(User::ROLES, &mut conn).unwrap();
+    assert_eq!(ann.roles, Some(vec![admin()]));
+    assert_eq!(ann.posts, None);
+    let bob = user(2, "bob").with_relations((User::POSTS,), &mut conn).unwrap();
+    assert_eq!(bob.posts, None);
+}
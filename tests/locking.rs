@@ -0,0 +1,39 @@
+mod common;
+
+use abstract_orm::OrmError;
+use common::*;
+
+#[test]
+fn insert_starts_at_the_default_version() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    assert_eq!(Tag::find(1, &mut conn).unwrap().unwrap().lock_version, 0);
+}
+
+#[test]
+fn update_increments_the_version() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    let mut tag = Tag::find(1, &mut conn).unwrap().unwrap();
+    tag.name = "diesel".into();
+    tag.update(&mut conn).unwrap();
+    assert_eq!(tag.lock_version, 1);
+    tag.update(&mut conn).unwrap();
+    assert_eq!(Tag::find(1, &mut conn).unwrap().unwrap().lock_version, 2);
+}
+
+#[test]
+fn update_of_a_stale_copy_fails() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    let mut first = Tag::find(1, &mut conn).unwrap().unwrap();
+    let mut second = first.clone();
+    first.name = "diesel".into();
+    first.update(&mut conn).unwrap();
+
+    second.name = "orm".into();
+    let err = second.update(&mut conn).unwrap_err();
+    assert!(matches!(err, OrmError::StaleObject { entity: "Tag" }));
+    assert_eq!(second.lock_version, 0);
+    assert_eq!(Tag::find(1, &mut conn).unwrap().unwrap().name, "diesel");
+}
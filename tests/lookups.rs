@@ -0,0 +1,49 @@
+mod common;
+
+use abstract_orm::Order;
+use common::schema::posts;
+use common::*;
+
+#[test]
+fn find_by_unique_field() {
+    let mut conn = seeded_connection();
+    assert_eq!(User::find_by_name("bob", &mut conn).unwrap(), Some(user(2, "bob")));
+    assert_eq!(User::find_by_name("cid", &mut conn).unwrap(), None);
+}
+
+#[test]
+fn find_all_by_indexed_field() {
+    let mut conn = seeded_connection();
+    assert_eq!(Post::find_all_by_published(true, None, &mut conn).unwrap(), vec![first_post()]);
+    assert_eq!(Post::find_all_by_published(false, None, &mut conn).unwrap(), vec![draft_post()]);
+
+    draft_post().soft_delete(&mut conn).unwrap();
+    assert!(Post::find_all_by_published(false, None, &mut conn).unwrap().is_empty());
+}
+
+#[test]
+fn find_all_by_a_null_value() {
+    let mut conn = seeded_connection();
+    assert_eq!(Post::find_all_by_category_id(None, None, &mut conn).unwrap(), vec![draft_post()]);
+    assert_eq!(Post::find_all_by_category_id(Some(1), None, &mut conn).unwrap(), vec![first_post()]);
+    assert!(Post::find_all_by_category_id(Some(2), None, &mut conn).unwrap().is_empty());
+    let posts = Post::find_all_by_category_id_with_user(None, None, &mut conn).unwrap();
+    assert_eq!(posts.len(), 1);
+    assert_eq!(posts[0].self_data, draft_post());
+}
+
+#[test]
+fn lookups_with_relations() {
+    let mut conn = seeded_connection();
+    let ann = User::find_by_name_with_posts("ann", &mut conn).unwrap().unwrap();
+    assert_eq!(ann.self_data, user(1, "ann"));
+    assert_eq!(ann.posts.map(|posts| posts.len()), Some(2));
+    assert!(User::find_by_name_with_posts("cid", &mut conn).unwrap().is_none());
+
+    Post::insert(NewPost { user_id: 2, category_id: None, title: "hello".into(), published: Some(true) }, &mut conn).unwrap();
+    let posts = Post::find_all_by_published_with_user(true, Some(Order::desc(posts::id)), &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].self_data.title, "hello");
+    assert_eq!(posts[0].user, Some(user(2, "bob")));
+    assert_eq!(posts[1].user, Some(user(1, "ann")));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use abstract_orm::{HasMany, Order};
+use common::schema::{posts, users};
+use common::*;
+
+/// `news` with a third post, `archive`, sorting before `first` by title
+/// but after it by id.
+fn connection_with_archive() -> diesel::SqliteConnection {
+    let mut conn = seeded_connection();
+    Post::insert(NewPost { user_id: 2, category_id: Some(1), title: "archive".into(), published: None }, &mut conn).unwrap();
+    conn
+}
+
+fn titles(posts: &[Post]) -> Vec<&str> {
+    posts.iter().map(|post| post.title.as_str()).collect()
+}
+
+#[test]
+fn find_all_follows_the_order() {
+    let mut conn = seeded_connection();
+    assert_eq!(User::find_all(Some(Order::desc(users::name)), &mut conn), vec![user(2, "bob"), user(1, "ann")]);
+    let posts = Post::find_all(Some(Order::asc(posts::title)), &mut conn);
+    assert_eq!(titles(&posts), vec!["draft", "first"]);
+}
+
+#[test]
+fn loaders_keep_the_order_through_grouping() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_with_posts(Some(Order::desc(users::name)), &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].self_data, user(2, "bob"));
+    assert_eq!(users[1].posts.as_ref().map(Vec::len), Some(2));
+
+    let users = User::find_all_eager(Some(Order::desc(users::name).then_asc(users::id)), &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].self_data, user(2, "bob"));
+    assert_eq!(users[1].posts.len(), 2);
+
+    let posts = Post::find_all_with_user(Some(Order::asc(posts::title)), &mut conn).unwrap();
+    assert_eq!(posts[0].self_data, draft_post());
+}
+
+#[test]
+fn children_follow_order_by() {
+    let mut conn = connection_with_archive();
+    assert_eq!(titles(&news().get_posts(&mut conn)), vec!["archive", "first"]);
+    assert_eq!(titles(&HasMany::<Post>::load_children(&news(), &mut conn).unwrap()), vec!["archive", "first"]);
+
+    let categories = Category::find_all_with_posts(None, &mut conn).unwrap();
+    assert_eq!(titles(categories[0].posts.as_ref().unwrap()), vec!["archive", "first"]);
+
+    let categories = Category::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(titles(&categories[0].posts), vec!["archive", "first"]);
+
+    let categories = Category::load_with(Category::POSTS, &mut conn).unwrap();
+    assert_eq!(titles(categories[0].posts.as_ref().unwrap()), vec!["archive", "first"]);
+}
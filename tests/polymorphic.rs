@@ -0,0 +1,56 @@
+mod common;
+
+use common::*;
+
+/// A photo with the same id as `first`, `nice` and `later` on the posts
+/// and `pretty` on the photo.
+fn connection_with_comments() -> diesel::SqliteConnection {
+    let mut conn = seeded_connection();
+    Photo::insert(NewPhoto { url: "sunset.jpg".into() }, &mut conn).unwrap();
+    for (body, commentable_type, commentable_id) in [("nice", "Post", 1), ("pretty", "Photo", 1), ("later", "Post", 2)] {
+        let data = NewComment { body: body.into(), commentable_type: commentable_type.into(), commentable_id };
+        Comment::insert(data, &mut conn).unwrap();
+    }
+    conn
+}
+
+fn sunset() -> Photo {
+    Photo { id: 1, url: "sunset.jpg".into() }
+}
+
+fn bodies(comments: &[Comment]) -> Vec<&str> {
+    comments.iter().map(|comment| comment.body.as_str()).collect()
+}
+
+#[test]
+fn get_commentable_follows_the_type_column() {
+    let mut conn = connection_with_comments();
+    let comments = Comment::find_all(None, &mut conn);
+    assert!(matches!(comments[0].get_commentable(&mut conn), Some(Commentable::Post(post)) if post == first_post()));
+    assert!(matches!(comments[1].get_commentable(&mut conn), Some(Commentable::Photo(photo)) if photo == sunset()));
+
+    let mut unknown = comments[0].clone();
+    unknown.commentable_type = "Video".into();
+    assert!(unknown.get_commentable(&mut conn).is_none());
+}
+
+#[test]
+fn targets_only_get_their_own_comments() {
+    let mut conn = connection_with_comments();
+    assert_eq!(bodies(&first_post().get_comments(&mut conn)), vec!["nice"]);
+    assert_eq!(bodies(&sunset().get_comments(&mut conn)), vec!["pretty"]);
+}
+
+#[test]
+fn loaders_filter_by_type() {
+    let mut conn = connection_with_comments();
+    let posts = Post::find_all_with_comments(None, &mut conn).unwrap();
+    assert_eq!(bodies(posts[0].comments.as_ref().unwrap()), vec!["nice"]);
+    assert_eq!(bodies(posts[1].comments.as_ref().unwrap()), vec!["later"]);
+
+    let photos = Photo::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(bodies(&photos[0].comments), vec!["pretty"]);
+
+    let posts = Post::load_with(Post::COMMENTS, &mut conn).unwrap();
+    assert_eq!(bodies(posts[0].comments.as_ref().unwrap()), vec!["nice"]);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::*;
+
+fn names(users: &[UserName]) -> Vec<&str> {
+    users.iter().map(|user| user.name.as_str()).collect()
+}
+
+#[test]
+fn find_all_as_projection() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_as::<UserName>(&mut conn).unwrap();
+    assert_eq!(names(&users), vec!["ann", "bob"]);
+    assert_eq!(User::find_all_as::<User>(&mut conn).unwrap(), vec![user(1, "ann"), user(2, "bob")]);
+}
+
+#[test]
+fn find_all_with_one_to_many_as_projections() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_with_posts_as::<UserName, PostTitle>(&mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].0.name, "ann");
+    let mut titles: Vec<&str> = users[0].1.iter().map(|post| post.title.as_str()).collect();
+    titles.sort();
+    assert_eq!(titles, vec!["draft", "first"]);
+    assert_eq!(users[1].0.name, "bob");
+    assert_eq!(users[1].1.len(), 0);
+}
+
+#[test]
+fn find_all_with_many_to_many_as_projections() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_with_roles_as::<UserName, Role>(&mut conn).unwrap();
+    assert_eq!(users[0].1, vec![admin()]);
+    assert_eq!(users[1].1, vec![]);
+}
+
+#[test]
+fn find_all_with_many_to_one_as_projections() {
+    let mut conn = seeded_connection();
+    let posts = Post::find_all_with_user_as::<PostTitle, UserName>(&mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert!(posts.iter().all(|(_, user)| user.name == "ann"));
+    let posts = Post::find_all_with_category_as::<PostTitle, Category>(&mut conn).unwrap();
+    let categories: Vec<(i64, Option<Category>)> = posts.into_iter().map(|(post, category)| (post.id, category)).collect();
+    assert_eq!(categories, vec![(1, Some(news())), (2, None)]);
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use abstract_orm::ConnectionSource;
+use common::*;
+use diesel::result::Error;
+use diesel::SqliteConnection;
+
+/// Writes to `primary`, which starts empty, and reads from the seeded
+/// `replica`, counting the checkouts of each.
+struct Routed {
+    primary: SqliteConnection,
+    replica: SqliteConnection,
+    reads: usize,
+    writes: usize,
+}
+
+impl Routed {
+    fn new() -> Self {
+        Routed { primary: empty_connection(), replica: seeded_connection(), reads: 0, writes: 0 }
+    }
+}
+
+impl ConnectionSource<SqliteConnection> for Routed {
+    type Guard<'s> = &'s mut SqliteConnection;
+
+    fn reader(&mut self) -> Result<&mut SqliteConnection, Error> {
+        self.reads += 1;
+        Ok(&mut self.replica)
+    }
+
+    fn writer(&mut self) -> Result<&mut SqliteConnection, Error> {
+        self.writes += 1;
+        Ok(&mut self.primary)
+    }
+}
+
+#[test]
+fn reads_go_to_the_reader() {
+    let mut source = Routed::new();
+    assert_eq!(User::find(1, &mut source).unwrap(), Some(user(1, "ann")));
+    assert_eq!(User::find_all(None, &mut source).len(), 2);
+    assert_eq!(User::find_by_name("bob", &mut source).unwrap(), Some(user(2, "bob")));
+    assert_eq!(user(1, "ann").get_posts(&mut source).len(), 2);
+    assert_eq!(User::load_with(User::POSTS, &mut source).unwrap().len(), 2);
+    assert_eq!(User::iter_all(1, &mut source).count(), 2);
+    assert_eq!(source.writes, 0);
+    assert_eq!(source.reads, 8);
+}
+
+#[test]
+fn writes_go_to_the_writer() {
+    let mut source = Routed::new();
+    let inserted = User::insert(NewUser { name: "cid".into() }, &mut source).unwrap();
+    assert_eq!(inserted.name, "cid");
+    assert_eq!(User::find_all(None, &mut source.primary), vec![user(1, "cid")]);
+    assert_eq!(User::find(3, &mut source.replica).unwrap(), None);
+
+    first_post().delete(&mut source).unwrap();
+    assert!(Post::find(1, &mut source.replica).unwrap().is_some());
+    assert_eq!(source.writes, 2);
+    assert_eq!(source.reads, 0);
+}
+
+#[cfg(feature = "r2d2")]
+#[test]
+fn replica_pools_route_reads_to_the_replica() {
+    use abstract_orm::ReplicaPools;
+    use diesel::connection::SimpleConnection;
+    use diesel::r2d2::{ConnectionManager, Pool};
+
+    let pool = || {
+        let pool = Pool::builder().max_size(1).build(ConnectionManager::<SqliteConnection>::new(":memory:")).unwrap();
+        pool.get().unwrap().batch_execute(CREATE_TABLES).unwrap();
+        pool
+    };
+    let mut pools = ReplicaPools::new(pool(), pool());
+    User::insert(NewUser { name: "ann".into() }, &mut pools.replica.get().unwrap()).unwrap();
+
+    assert_eq!(User::find(1, &mut pools).unwrap(), Some(user(1, "ann")));
+    User::insert(NewUser { name: "bob".into() }, &mut pools).unwrap();
+    assert_eq!(User::find_all(None, &mut pools.primary.get().unwrap()), vec![user(1, "bob")]);
+    assert_eq!(User::find_all(None, &mut pools), vec![user(1, "ann")]);
+}
+
+#[cfg(feature = "r2d2")]
+#[test]
+fn pooled_connection_is_a_source() {
+    use diesel::connection::SimpleConnection;
+    use diesel::r2d2::{ConnectionManager, Pool};
+
+    let pool = Pool::builder().max_size(1).build(ConnectionManager::<SqliteConnection>::new(":memory:")).unwrap();
+    pool.get().unwrap().batch_execute(CREATE_TABLES).unwrap();
+    User::insert(NewUser { name: "ann".into() }, &mut pool.get().unwrap()).unwrap();
+    assert_eq!(User::find(1, &mut pool.get().unwrap()).unwrap(), Some(user(1, "ann")));
+    assert_eq!(User::iter_all(1, &mut pool.get().unwrap()).count(), 1);
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::*;
+
+/// `ann` also gets the `editor` role, which is not an admin role.
+fn connection_with_editor() -> diesel::SqliteConnection {
+    let mut conn = seeded_connection();
+    Role::insert(NewRole { name: "editor".into() }, &mut conn).unwrap();
+    UserToRole::insert(NewUserToRole { user_id: 1, role_id: 2, granted_by: "ann".into() }, &mut conn).unwrap();
+    conn
+}
+
+#[test]
+fn getters_apply_the_condition() {
+    let mut conn = connection_with_editor();
+    let ann = user(1, "ann");
+    assert_eq!(ann.get_published_posts(&mut conn), vec![first_post()]);
+    assert_eq!(ann.get_posts(&mut conn).len(), 2);
+    assert_eq!(ann.get_admin_roles(&mut conn), vec![admin()]);
+    assert_eq!(ann.get_roles(&mut conn).len(), 2);
+}
+
+#[test]
+fn find_all_with_keeps_parents_without_matches() {
+    let mut conn = connection_with_editor();
+    let users = User::find_all_with_published_posts(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].published_posts, Some(vec![first_post()]));
+    assert_eq!(users[1].self_data, user(2, "bob"));
+    assert_eq!(users[1].published_posts, None);
+
+    let users = User::find_all_with_admin_roles(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].admin_roles, Some(vec![admin()]));
+    assert_eq!(users[1].admin_roles, None);
+}
+
+#[test]
+fn find_all_eager_fills_scoped_relations() {
+    let mut conn = seeded_connection();
+    let users = User::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].posts.len(), 2);
+    assert_eq!(users[0].published_posts, vec![first_post()]);
+    assert_eq!(users[0].admin_roles, vec![admin()]);
+    assert_eq!(users[1].published_posts, vec![]);
+}
+
+#[test]
+fn lazy_loaders_apply_the_condition() {
+    let mut conn = connection_with_editor();
+    let users = User::load_with((User::PUBLISHED_POSTS, User::ADMIN_ROLES), &mut conn).unwrap();
+    assert_eq!(users[0].published_posts, Some(vec![first_post()]));
+    assert_eq!(users[0].admin_roles, Some(vec![admin()]));
+    assert_eq!(users[1].published_posts, None);
+
+    let with_posts = User::find_all_with_published_posts_as::<UserName, PostTitle>(&mut conn).unwrap();
+    assert_eq!(with_posts[0].1.len(), 1);
+    assert_eq!(with_posts[0].1[0].title, "first");
+}
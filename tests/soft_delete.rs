@@ -0,0 +1,102 @@
+mod common;
+
+use abstract_orm::{Entity, Order};
+use common::*;
+
+#[test]
+fn soft_deleted_rows_are_left_out_of_find() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    assert_eq!(Post::find(2, &mut conn).unwrap(), None);
+    assert_eq!(Post::find_all(None, &mut conn), vec![first_post()]);
+    assert_eq!(<Post as Entity>::find_all(&mut conn).unwrap(), vec![first_post()]);
+}
+
+#[test]
+fn find_all_with_deleted_keeps_them() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    let posts = Post::find_all_with_deleted(None, &mut conn);
+    assert_eq!(posts.len(), 2);
+    assert!(posts[1].deleted_at.is_some());
+
+    let posts = Post::find_all_with_deleted(Some(Order::desc(common::schema::posts::id)), &mut conn);
+    assert_eq!(posts[1], first_post());
+    assert!(posts[0].deleted_at.is_some());
+}
+
+#[test]
+fn restore_brings_the_row_back() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    draft_post().restore(&mut conn).unwrap();
+    assert_eq!(Post::find(2, &mut conn).unwrap(), Some(draft_post()));
+}
+
+#[test]
+fn getters_leave_out_soft_deleted_children() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    admin().soft_delete(&mut conn).unwrap();
+    let ann = user(1, "ann");
+    assert_eq!(ann.get_posts(&mut conn), vec![first_post()]);
+    assert_eq!(ann.get_roles(&mut conn), vec![]);
+    news().soft_delete(&mut conn).unwrap();
+    assert_eq!(first_post().get_category(&mut conn), None);
+}
+
+#[test]
+fn getters_keep_the_soft_deleted_parent() {
+    let mut conn = empty_connection();
+    Author::insert(NewAuthor { name: "ann".into() }, &mut conn).unwrap();
+    Book::insert(NewBook { author_id: 1, title: "first".into() }, &mut conn).unwrap();
+    let author = Author::find(1, &mut conn).unwrap().unwrap();
+    author.soft_delete(&mut conn).unwrap();
+
+    let book = Book::find(1, &mut conn).unwrap().unwrap();
+    let parent = book.get_author(&mut conn);
+    assert_eq!(parent.id, author.id);
+    assert!(parent.deleted_at.is_some());
+}
+
+#[test]
+fn loaders_keep_the_parent_of_soft_deleted_children() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    first_post().soft_delete(&mut conn).unwrap();
+    admin().soft_delete(&mut conn).unwrap();
+
+    let with_posts = User::find_all_with_posts(None, &mut conn).unwrap();
+    assert_eq!(with_posts.len(), 2);
+    assert_eq!(with_posts[0].posts, None);
+
+    let with_roles = User::find_all_with_roles(None, &mut conn).unwrap();
+    assert_eq!(with_roles.len(), 2);
+    assert_eq!(with_roles[0].roles, None);
+
+    let lazy = User::load_with((User::POSTS, User::ROLES), &mut conn).unwrap();
+    assert_eq!(lazy.len(), 2);
+    assert_eq!((lazy[0].posts.clone(), lazy[0].roles.clone()), (None, None));
+}
+
+#[test]
+fn left_joined_parent_is_none_once_soft_deleted() {
+    let mut conn = seeded_connection();
+    news().soft_delete(&mut conn).unwrap();
+    let posts = Post::find_all_with_category(None, &mut conn).unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].category, None);
+}
+
+#[test]
+fn projections_leave_out_soft_deleted_rows() {
+    let mut conn = seeded_connection();
+    draft_post().soft_delete(&mut conn).unwrap();
+    let titles: Vec<String> = Post::find_all_as::<PostTitle>(&mut conn).unwrap()
+        .into_iter()
+        .map(|post| post.title)
+        .collect();
+    assert_eq!(titles, vec!["first".to_string()]);
+    let with_posts = User::find_all_with_posts_as::<UserName, PostTitle>(&mut conn).unwrap();
+    assert_eq!(with_posts[0].1.len(), 1);
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::*;
+
+/// `nice` and `later` on the posts of `ann`, and `pretty` on a photo with
+/// the same id as `first`.
+fn connection_with_comments() -> diesel::SqliteConnection {
+    let mut conn = seeded_connection();
+    Photo::insert(NewPhoto { url: "sunset.jpg".into() }, &mut conn).unwrap();
+    for (body, commentable_type, commentable_id) in [("nice", "Post", 1), ("pretty", "Photo", 1), ("later", "Post", 2)] {
+        let data = NewComment { body: body.into(), commentable_type: commentable_type.into(), commentable_id };
+        Comment::insert(data, &mut conn).unwrap();
+    }
+    conn
+}
+
+fn bodies(comments: &[Comment]) -> Vec<&str> {
+    comments.iter().map(|comment| comment.body.as_str()).collect()
+}
+
+#[test]
+fn getter_goes_through_the_relation() {
+    let mut conn = connection_with_comments();
+    assert_eq!(bodies(&user(1, "ann").get_comments_on_my_posts(&mut conn)), vec!["nice", "later"]);
+    assert!(user(2, "bob").get_comments_on_my_posts(&mut conn).is_empty());
+
+    draft_post().soft_delete(&mut conn).unwrap();
+    assert_eq!(bodies(&user(1, "ann").get_comments_on_my_posts(&mut conn)), vec!["nice"]);
+}
+
+#[test]
+fn loaders_go_through_the_relation() {
+    let mut conn = connection_with_comments();
+    let users = User::find_all_with_comments_on_my_posts(None, &mut conn).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(bodies(users[0].comments_on_my_posts.as_ref().unwrap()), vec!["nice", "later"]);
+    assert_eq!(users[1].comments_on_my_posts, None);
+
+    let users = User::load_with(User::COMMENTS_ON_MY_POSTS, &mut conn).unwrap();
+    assert_eq!(bodies(users[0].comments_on_my_posts.as_ref().unwrap()), vec!["nice", "later"]);
+
+    let with_comments = User::find_all_with_comments_on_my_posts_as::<UserName, Comment>(&mut conn).unwrap();
+    assert_eq!(bodies(&with_comments[0].1), vec!["nice", "later"]);
+    assert!(with_comments[1].1.is_empty());
+}
+
+#[test]
+fn find_all_eager_fills_the_relation() {
+    let mut conn = connection_with_comments();
+    let users = User::find_all_eager(None, &mut conn).unwrap();
+    assert_eq!(users[0].posts.len(), 2);
+    assert_eq!(bodies(&users[0].comments_on_my_posts), vec!["nice", "later"]);
+    assert!(users[1].comments_on_my_posts.is_empty());
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::*;
+use diesel::connection::SimpleConnection;
+
+#[test]
+fn insert_fills_the_timestamps() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    let tag = Tag::find(1, &mut conn).unwrap().unwrap();
+    assert!(!tag.created_at.is_empty());
+    assert_eq!(tag.created_at, tag.updated_at);
+}
+
+#[test]
+fn update_bumps_updated_at_only() {
+    let mut conn = empty_connection();
+    Tag::insert(NewTag { name: "rust".into() }, &mut conn).unwrap();
+    conn.batch_execute("UPDATE tags SET created_at = '2000-01-01 00:00:00', updated_at = '2000-01-01 00:00:00'").unwrap();
+    let mut tag = Tag::find(1, &mut conn).unwrap().unwrap();
+    tag.name = "diesel".into();
+    tag.update(&mut conn).unwrap();
+
+    let updated = Tag::find(1, &mut conn).unwrap().unwrap();
+    assert_eq!(updated.name, "diesel");
+    assert_eq!(updated.created_at, "2000-01-01 00:00:00");
+    assert_ne!(updated.updated_at, "2000-01-01 00:00:00");
+}
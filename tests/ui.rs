@@ -0,0 +1,8 @@
+// The expected compiler output is recorded for the default feature set only.
+#![cfg(not(feature = "async"))]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,20 @@
+use abstract_orm::Joinable;
+use diesel::prelude::*;
+
+diesel::table! {
+    countries (code) {
+        code -> Integer,
+        name -> Text,
+    }
+}
+
+#[derive(Queryable, Selectable, Identifiable, Joinable)]
+#[diesel(table_name = countries)]
+#[diesel(primary_key(code))]
+#[orm(backend = sqlite, hooks)]
+pub struct Country {
+    pub code: i32,
+    pub name: String,
+}
+
+fn main() {}
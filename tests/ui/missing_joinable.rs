@@ -0,0 +1,40 @@
+use abstract_orm::Joinable;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+diesel::table! {
+    users (id) {
+        id -> BigInt,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    posts (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(users, posts);
+
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Clone, Joinable)]
+#[diesel(table_name = users)]
+#[orm(backend = sqlite)]
+#[one_to_many(posts = Post)]
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize, Clone, Joinable)]
+#[diesel(table_name = posts)]
+#[diesel(belongs_to(User))]
+#[orm(backend = sqlite)]
+pub struct Post {
+    pub id: i64,
+    #[many_to_one(User)]
+    pub user_id: i64,
+}
+
+fn main() {}
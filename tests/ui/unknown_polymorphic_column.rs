@@ -0,0 +1,22 @@
+use abstract_orm::Joinable;
+use diesel::prelude::*;
+
+diesel::table! {
+    comments (id) {
+        id -> BigInt,
+        body -> Text,
+        commentable_id -> BigInt,
+    }
+}
+
+#[derive(Queryable, Selectable, Identifiable, Joinable)]
+#[diesel(table_name = comments)]
+#[orm(backend = sqlite)]
+#[polymorphic(commentable, types(Post))]
+pub struct Comment {
+    pub id: i64,
+    pub body: String,
+    pub commentable_id: i64,
+}
+
+fn main() {}
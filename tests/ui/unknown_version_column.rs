@@ -0,0 +1,19 @@
+use abstract_orm::Joinable;
+use diesel::prelude::*;
+
+diesel::table! {
+    users (id) {
+        id -> BigInt,
+        name -> Text,
+    }
+}
+
+#[derive(Queryable, Selectable, Identifiable, Joinable)]
+#[diesel(table_name = users)]
+#[orm(backend = sqlite, version = lock_version)]
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+fn main() {}
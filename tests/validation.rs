@@ -0,0 +1,47 @@
+mod common;
+
+use abstract_orm::{FieldError, OrmError};
+use common::*;
+
+fn new_account(email: &str, name: &str, age: Option<i32>) -> NewAccount {
+    NewAccount { email: email.into(), name: name.into(), age }
+}
+
+#[test]
+fn valid_data_is_inserted() {
+    let mut conn = empty_connection();
+    assert_eq!(new_account("ann@example.com", "ann", None).validate(), Ok(()));
+    Account::insert(new_account("ann@example.com", "ann", Some(30)), &mut conn).unwrap();
+    assert_eq!(Account::find_all(None, &mut conn).len(), 1);
+}
+
+#[test]
+fn validate_returns_every_failing_field() {
+    let errors = new_account("not-an-email", "a", Some(-1)).validate().unwrap_err();
+    assert_eq!(errors, vec![
+        FieldError { field: "email", message: "must be a valid email address".into() },
+        FieldError { field: "name", message: "must be at least 2 characters".into() },
+        FieldError { field: "age", message: "must be at least 0".into() },
+    ]);
+}
+
+#[test]
+fn insert_rejects_invalid_data() {
+    let mut conn = empty_connection();
+    let Err(err) = Account::insert(new_account("ann@example", "ann", None), &mut conn) else {
+        panic!("an invalid account was inserted");
+    };
+    assert!(matches!(&err, OrmError::Validation(errors) if errors.len() == 1));
+    assert_eq!(err.to_string(), "invalid data: `email` must be a valid email address");
+    assert!(Account::find_all(None, &mut conn).is_empty());
+}
+
+#[test]
+fn update_rejects_invalid_data() {
+    let mut conn = empty_connection();
+    Account::insert(new_account("ann@example.com", "ann", None), &mut conn).unwrap();
+    let mut account = Account::find(1, &mut conn).unwrap().unwrap();
+    account.age = Some(200);
+    assert!(matches!(account.update(&mut conn), Err(OrmError::Validation(_))));
+    assert_eq!(Account::find(1, &mut conn).unwrap().unwrap().age, None);
+}